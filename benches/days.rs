@@ -0,0 +1,39 @@
+/*
+ * Benchmarks both parts of every day with criterion, on the real input if
+ * `src/inputs/NN.txt` (or `NN.txt.enc`) exists and on the example otherwise.
+ *
+ * `cargo bench-days` runs them all, `cargo bench-days day14` a single day.
+ * Reports with confidence intervals go to `target/criterion`: `report/index.html`,
+ * and `<day>/<part>/<input>/new/estimates.json` per benchmark. To compare against
+ * a named baseline, save it with `cargo bench-days --save-baseline before`, then
+ * run `cargo bench-days --baseline before` after the change.
+ */
+use advent_of_code::days;
+use advent_of_code::runner::Day;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+
+/// The input to benchmark a day on, named after the folder it's from.
+fn input(day: &Day) -> (&'static str, String) {
+    if advent_of_code::has_file("inputs", day.day) {
+        ("inputs", advent_of_code::read_file("inputs", day.day))
+    } else {
+        ("examples", day.example.to_string())
+    }
+}
+
+fn bench_days(c: &mut Criterion) {
+    for day in days::ALL {
+        let (folder, input) = input(day);
+        let mut group = c.benchmark_group(format!("day{:02}", day.day));
+        for (name, part) in ["part_one", "part_two"].into_iter().zip(day.parts) {
+            group.bench_with_input(BenchmarkId::new(name, folder), &input, |b, input| {
+                b.iter(|| part(black_box(input)))
+            });
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
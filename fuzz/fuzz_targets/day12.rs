@@ -0,0 +1,8 @@
+#![no_main]
+use advent_of_code::days::day12::{part_one, part_two};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = part_one(input);
+    let _ = part_two(input);
+});
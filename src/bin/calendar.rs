@@ -0,0 +1,386 @@
+/*
+ * An interactive calendar of the event, e.g. `cargo calendar`.
+ *
+ * Shows every day's stars (from the submission ledger), latest answers and timings,
+ * and test status. For the selected day, `r` runs the solution in-process on its
+ * input, `t` checks its parts in-process against the example answers, `p` shows
+ * the puzzle description and `1`/`2` submit the latest answer to a part after
+ * confirming. Arrow keys or `hjkl` move, `q` quits.
+ */
+use advent_of_code::runner::{self, Day, Outcome};
+use advent_of_code::{days, markdown, submit};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Text};
+use ratatui::widgets::{Block, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+use std::collections::BTreeMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+use std::{fs, io};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Tests {
+    NotRun,
+    Running,
+    Passed,
+    Failed(String),
+}
+
+/// Results of the background work started from the calendar.
+enum Update {
+    /// The outcomes, or why the input couldn't be read.
+    Ran(u8, Result<[Outcome; 2], String>),
+    Tested(u8, Tests),
+    Submitted(u8, u8, Result<String, String>),
+}
+
+enum Mode {
+    Calendar,
+    Puzzle {
+        text: String,
+        scroll: u16,
+    },
+    /// Waiting for `y` to submit `answer` to `part`.
+    Confirm {
+        part: u8,
+        answer: String,
+    },
+}
+
+struct App {
+    days: BTreeMap<u8, Day>,
+    selected: u8,
+    ledger: BTreeMap<(u8, u8), String>,
+    running: Option<u8>,
+    outcomes: BTreeMap<u8, [Outcome; 2]>,
+    tests: BTreeMap<u8, Tests>,
+    mode: Mode,
+    status: String,
+    updates: (Sender<Update>, Receiver<Update>),
+}
+
+impl App {
+    fn new() -> Self {
+        Self {
+            days: days::ALL.iter().map(|&day| (day.day, day)).collect(),
+            selected: 1,
+            ledger: submit::ledger(),
+            running: None,
+            outcomes: BTreeMap::new(),
+            tests: BTreeMap::new(),
+            mode: Mode::Calendar,
+            status: String::new(),
+            updates: mpsc::channel(),
+        }
+    }
+
+    fn stars(&self, day: u8) -> usize {
+        (1..=2)
+            .filter(|&part| self.ledger.contains_key(&(day, part)))
+            .count()
+    }
+
+    fn run_selected(&mut self) {
+        let Some(&day) = self.days.get(&self.selected) else {
+            return;
+        };
+        if self.running.is_some() {
+            self.status = "Already running a solution.".to_string();
+            return;
+        }
+        if !advent_of_code::has_file("inputs", day.day) {
+            self.status = format!(
+                "No input for day {:02}; `cargo download {}`.",
+                day.day, day.day
+            );
+            return;
+        }
+        self.running = Some(day.day);
+        self.status = format!("Running day {:02}...", day.day);
+        let sender = self.updates.0.clone();
+        thread::spawn(move || {
+            let outcomes =
+                advent_of_code::read_input(advent_of_code::profile().as_deref(), day.day)
+                    .map(|input| day.parts.map(|part| runner::run(part, &input)));
+            let _ = sender.send(Update::Ran(day.day, outcomes));
+        });
+    }
+
+    fn test_selected(&mut self) {
+        let Some(&day) = self.days.get(&self.selected) else {
+            return;
+        };
+        if self.tests.get(&day.day) == Some(&Tests::Running) {
+            return;
+        }
+        self.tests.insert(day.day, Tests::Running);
+        let sender = self.updates.0.clone();
+        thread::spawn(move || {
+            let _ = sender.send(Update::Tested(day.day, test(&day)));
+        });
+    }
+
+    fn show_puzzle(&mut self) {
+        let path = format!("src/puzzles/{:02}.md", self.selected);
+        match fs::read_to_string(&path) {
+            Ok(puzzle) => {
+                let text = markdown::strip_ansi(&markdown::render(&puzzle, 80));
+                self.mode = Mode::Puzzle { text, scroll: 0 };
+            }
+            Err(_) => {
+                self.status = format!("No puzzle description; `cargo download {}`.", self.selected);
+            }
+        }
+    }
+
+    fn confirm_submit(&mut self, part: u8) {
+        let answer = self
+            .outcomes
+            .get(&self.selected)
+            .and_then(|outcomes| outcomes[part as usize - 1].answer.clone().ok().flatten());
+        match answer {
+            Some(answer) => self.mode = Mode::Confirm { part, answer },
+            None => self.status = format!("No answer to part {} yet; run it first.", part),
+        }
+    }
+
+    fn submit(&mut self, part: u8, answer: String) {
+        let (day, sender) = (self.selected, self.updates.0.clone());
+        self.status = format!("Submitting {} to day {:02} part {}...", answer, day, part);
+        thread::spawn(move || {
+            let result = submit::submit_answer(day as u32, part as u32, &answer);
+            let _ = sender.send(Update::Submitted(day, part, result));
+        });
+    }
+
+    fn update(&mut self, update: Update) {
+        match update {
+            Update::Ran(day, Ok(outcomes)) => {
+                self.running = None;
+                self.status = format!("Day {:02} done.", day);
+                self.outcomes.insert(day, outcomes);
+            }
+            Update::Ran(day, Err(e)) => {
+                self.running = None;
+                self.status = format!("Day {:02}: {}", day, e);
+            }
+            Update::Tested(day, tests) => {
+                self.status = format!("Day {:02} tests done.", day);
+                self.tests.insert(day, tests);
+            }
+            Update::Submitted(day, part, result) => {
+                self.ledger = submit::ledger();
+                self.status = match result {
+                    Ok(output) => {
+                        let line = output
+                            .lines()
+                            .find(|line| line.contains("answer"))
+                            .unwrap_or("Submitted.");
+                        format!("Day {:02} part {}: {}", day, part, line.trim())
+                    }
+                    Err(e) => e.lines().next().unwrap_or_default().to_string(),
+                };
+            }
+        }
+    }
+
+    /// Handles a key press, returning `false` to quit.
+    fn key(&mut self, code: KeyCode) -> bool {
+        match &mut self.mode {
+            Mode::Puzzle { scroll, .. } => match code {
+                KeyCode::Down | KeyCode::Char('j') => *scroll += 1,
+                KeyCode::Up | KeyCode::Char('k') => *scroll = scroll.saturating_sub(1),
+                KeyCode::PageDown | KeyCode::Char(' ') => *scroll += 20,
+                KeyCode::PageUp => *scroll = scroll.saturating_sub(20),
+                KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('p') => {
+                    self.mode = Mode::Calendar
+                }
+                _ => {}
+            },
+            Mode::Confirm { part, answer } => {
+                let (part, answer) = (*part, answer.clone());
+                self.mode = Mode::Calendar;
+                if code == KeyCode::Char('y') {
+                    self.submit(part, answer);
+                } else {
+                    self.status = "Not submitted.".to_string();
+                }
+            }
+            Mode::Calendar => {
+                let day = self.selected as i32;
+                let moved = match code {
+                    KeyCode::Left | KeyCode::Char('h') => day - 1,
+                    KeyCode::Right | KeyCode::Char('l') => day + 1,
+                    KeyCode::Up | KeyCode::Char('k') => day - 5,
+                    KeyCode::Down | KeyCode::Char('j') => day + 5,
+                    _ => day,
+                };
+                self.selected = moved.clamp(1, 25) as u8;
+                match code {
+                    KeyCode::Char('q') | KeyCode::Esc => return false,
+                    KeyCode::Char('r') | KeyCode::Enter => self.run_selected(),
+                    KeyCode::Char('t') => self.test_selected(),
+                    KeyCode::Char('p') => self.show_puzzle(),
+                    KeyCode::Char('1') => self.confirm_submit(1),
+                    KeyCode::Char('2') => self.confirm_submit(2),
+                    _ => {}
+                }
+            }
+        }
+        true
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [main, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(2)]).areas(frame.area());
+        if let Mode::Puzzle { text, scroll } = &self.mode {
+            let puzzle = Paragraph::new(text.as_str())
+                .block(Block::bordered().title(format!(" Day {} ", self.selected)))
+                .wrap(Wrap { trim: false })
+                .scroll((*scroll, 0));
+            frame.render_widget(puzzle, main);
+        } else {
+            let [calendar, details] =
+                Layout::horizontal([Constraint::Length(5 * 10 + 2), Constraint::Min(30)])
+                    .areas(main);
+            self.draw_calendar(frame, calendar);
+            self.draw_details(frame, details);
+        }
+
+        let help = match &self.mode {
+            Mode::Calendar => "r run  t test  p puzzle  1/2 submit  q quit".to_string(),
+            Mode::Puzzle { .. } => "↑/↓ scroll  q back".to_string(),
+            Mode::Confirm { part, answer } => format!(
+                "Submit {} to day {} part {}? y/n",
+                answer, self.selected, part
+            ),
+        };
+        let footer_text = Text::from(vec![Line::from(self.status.as_str()), help.dim().into()]);
+        frame.render_widget(Paragraph::new(footer_text), footer);
+    }
+
+    fn draw_calendar(&self, frame: &mut Frame, area: Rect) {
+        let block = Block::bordered().title(" 🎄 Advent of Code ");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        let rows = Layout::vertical([Constraint::Length(4); 5]).split(inner);
+        for (row, row_area) in rows.iter().enumerate() {
+            let cells = Layout::horizontal([Constraint::Length(10); 5]).split(*row_area);
+            for (col, cell) in cells.iter().enumerate() {
+                let day = (row * 5 + col + 1) as u8;
+                let stars = match self.stars(day) {
+                    2 => "★★".yellow(),
+                    1 => "★☆".yellow(),
+                    _ => "☆☆".dark_gray(),
+                };
+                let mut style = Style::default();
+                if !self.days.contains_key(&day) {
+                    style = style.fg(Color::DarkGray);
+                }
+                if day == self.selected {
+                    style = style.add_modifier(Modifier::REVERSED);
+                }
+                let text = Text::from(vec![Line::from(format!("Day {}", day)), stars.into()]);
+                let widget = Paragraph::new(text)
+                    .centered()
+                    .block(Block::bordered().style(style));
+                frame.render_widget(widget, *cell);
+            }
+        }
+    }
+
+    fn draw_details(&self, frame: &mut Frame, area: Rect) {
+        let day = self.selected;
+        let mut lines = Vec::new();
+        if !self.days.contains_key(&day) {
+            lines.push(Line::from(format!(
+                "No solution yet; `cargo scaffold {}`.",
+                day
+            )));
+        }
+        for part in 1..=2 {
+            lines.push(Line::from(format!("Part {}", part).bold()));
+            match self.ledger.get(&(day, part)) {
+                Some(answer) => lines.push(Line::from(format!("  ★ {}", answer).yellow())),
+                None => lines.push(Line::from("  not submitted".dark_gray())),
+            }
+            if let Some(outcomes) = self.outcomes.get(&day) {
+                let outcome = &outcomes[part as usize - 1];
+                let answer = match &outcome.answer {
+                    Ok(Some(answer)) => answer.clone(),
+                    Ok(None) => "not solved".to_string(),
+                    Err(panic) => format!("panicked: {}", panic),
+                };
+                lines.push(Line::from(format!(
+                    "  latest: {} ({:.2?})",
+                    answer, outcome.elapsed
+                )));
+            }
+        }
+        lines.push(Line::default());
+        let tests = match self.tests.get(&day).unwrap_or(&Tests::NotRun) {
+            Tests::NotRun => "not run".dark_gray(),
+            Tests::Running => "running...".into(),
+            Tests::Passed => "passed".green(),
+            Tests::Failed(failed) => format!("failed {}", failed).red(),
+        };
+        lines.push(Line::from(vec!["Tests: ".bold(), tests]));
+        if self.running == Some(day) {
+            lines.push(Line::from("Running..."));
+        }
+
+        let details = Paragraph::new(lines)
+            .block(Block::bordered().title(format!(" Day {} ", day)))
+            .wrap(Wrap { trim: false });
+        frame.render_widget(details, area);
+    }
+}
+
+/// Checks every part with an expected answer on the day's example. A day without
+/// any fails, as nothing was tested.
+fn test(day: &Day) -> Tests {
+    let checked = (1..=2)
+        .zip(day.parts)
+        .zip(day.answers)
+        .filter_map(|((part, solver), expected)| Some((part, solver, expected?)))
+        .collect::<Vec<_>>();
+    if checked.is_empty() {
+        return Tests::Failed("no example answers".to_string());
+    }
+    let failed = checked
+        .into_iter()
+        .filter(|&(_, solver, expected)| !runner::run(solver, day.example).is(expected))
+        .map(|(part, _, _)| format!("part {}", part))
+        .collect::<Vec<_>>();
+    if failed.is_empty() {
+        Tests::Passed
+    } else {
+        Tests::Failed(failed.join(", "))
+    }
+}
+
+fn run(terminal: &mut DefaultTerminal, mut app: App) -> io::Result<()> {
+    loop {
+        while let Ok(update) = app.updates.1.try_recv() {
+            app.update(update);
+        }
+        terminal.draw(|frame| app.draw(frame))?;
+        if event::poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !app.key(key.code) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+fn main() -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, App::new());
+    ratatui::restore();
+    result
+}
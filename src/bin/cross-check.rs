@@ -0,0 +1,100 @@
+/*
+ * Runs a day against every input profile and compares with the expected answers,
+ * e.g. `cargo cross-check 8`, to catch assumptions that only hold for one input.
+ *
+ * Profiles are the directories in `src/inputs`, each with its `NN.txt` (or
+ * `NN.txt.enc`) and expected answers in `answers.txt`, one `<day> <part> <answer>`
+ * per line like the submission ledger. The default inputs in `src/inputs` are
+ * checked against the ledger. Every profile runs in this process, so use a release
+ * build, as `cargo cross-check` does.
+ */
+use advent_of_code::encrypt::encrypted_path;
+use advent_of_code::runner::{self, Day, Outcome};
+use advent_of_code::submit::{ledger, read_answers};
+use advent_of_code::{days, input_path, profiles, read_input, ANSI_BOLD, ANSI_RESET};
+use std::process;
+
+/// Runs both parts in-process on `profile`'s input, or says why it can't be read.
+fn run(day: &Day, profile: Option<&str>) -> Result<[Outcome; 2], String> {
+    let input = read_input(profile, day.day)?;
+    Ok(day.parts.map(|part| runner::run(part, &input)))
+}
+
+fn main() {
+    let day: u8 = match pico_args::Arguments::from_env().free_from_str() {
+        Ok(day) => day,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo cross-check 8`");
+            process::exit(1);
+        }
+    };
+    let Some(day) = days::ALL.iter().find(|d| d.day == day) else {
+        eprintln!("Day {:02} has no solution.", day);
+        process::exit(1);
+    };
+
+    let has_input = |profile: Option<&str>| {
+        let path = input_path(profile, day.day);
+        path.exists() || encrypted_path(&path).exists()
+    };
+    // the ledger holds the account's own answers, so it only applies to the default
+    // inputs; a profile without `answers.txt` has no expected answers.
+    let mut checks = Vec::new();
+    if has_input(None) {
+        checks.push((None, ledger()));
+    }
+    for profile in profiles() {
+        if has_input(Some(&profile)) {
+            let answers = input_path(Some(&profile), day.day).with_file_name("answers.txt");
+            checks.push((Some(profile), read_answers(&answers)));
+        }
+    }
+    if checks.is_empty() {
+        eprintln!("No inputs for day {:02} in any profile.", day.day);
+        process::exit(1);
+    }
+
+    let mut failures = 0;
+    for (profile, expected) in checks {
+        let name = profile.as_deref().unwrap_or("(default)");
+        let outcomes = match run(day, profile.as_deref()) {
+            Ok(outcomes) => outcomes,
+            Err(e) => {
+                failures += 1;
+                println!("{}{}{}: {}", ANSI_BOLD, name, ANSI_RESET, e);
+                continue;
+            }
+        };
+        for (part, outcome) in (1..=2).zip(outcomes) {
+            let result = match (expected.get(&(day.day, part)), outcome.answer) {
+                (_, Err(panic)) => {
+                    failures += 1;
+                    format!("crashed: {}", panic)
+                }
+                (Some(expected), Ok(Some(got))) if got == *expected => "ok".to_string(),
+                (Some(expected), Ok(got)) => {
+                    failures += 1;
+                    format!(
+                        "FAILED, expected {} but got {}",
+                        expected,
+                        got.as_deref().unwrap_or("nothing")
+                    )
+                }
+                (None, Ok(got)) => format!(
+                    "{}, no expected answer",
+                    got.as_deref().unwrap_or("not solved")
+                ),
+            };
+            println!(
+                "{}{}{} part {}: {}",
+                ANSI_BOLD, name, ANSI_RESET, part, result
+            );
+        }
+    }
+
+    if failures > 0 {
+        println!("---");
+        println!("{} check(s) failed for day {:02}.", failures, day.day);
+        process::exit(1);
+    }
+}
@@ -9,11 +9,14 @@ use std::{fs, process};
 
 struct Args {
     day: u8,
+    /// Only the puzzle description, e.g. to add part two once it's unlocked.
+    puzzle_only: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
+        puzzle_only: args.contains("--puzzle"),
         day: args.free_from_str()?,
     })
 }
@@ -47,6 +50,12 @@ fn main() {
 
     let day_padded = format!("{:02}", args.day);
     let input_path = format!("src/inputs/{}.txt", day_padded);
+    let puzzle_path = format!("src/puzzles/{}.md", day_padded);
+
+    if let Err(e) = fs::create_dir_all("src/puzzles") {
+        eprintln!("could not create the puzzles directory: {}", e);
+        exit_with_status(1, &tmp_file_path);
+    }
 
     // check if aoc binary exists and is callable.
     if Command::new("aoc").arg("-V").output().is_err() {
@@ -64,14 +73,17 @@ fn main() {
     cmd_args.append(&mut vec![
         "--input-file".into(),
         tmp_file_path.to_string_lossy().to_string(),
+        "--puzzle-file".into(),
+        puzzle_path.clone(),
         "--day".into(),
         args.day.to_string(),
-        "-I".into(),
-        "-o".into(),
-        "download".into(),
     ]);
+    if args.puzzle_only {
+        cmd_args.push("-P".into());
+    }
+    cmd_args.append(&mut vec!["-o".into(), "download".into()]);
 
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
+    println!("Downloading with >aoc {}", cmd_args.join(" "));
 
     match Command::new("aoc").args(cmd_args).output() {
         Ok(cmd_output) => {
@@ -91,9 +103,17 @@ fn main() {
         }
     }
 
+    println!("---");
+    println!(
+        "🎄 Successfully wrote puzzle description to \"{}\". Type `cargo read {}` to read it.",
+        &puzzle_path, &day_padded
+    );
+    if args.puzzle_only {
+        exit_with_status(0, &tmp_file_path);
+    }
+
     match fs::rename(&tmp_file_path, &input_path) {
         Ok(_) => {
-            println!("---");
             println!("🎄 Successfully wrote input to \"{}\".", &input_path);
             exit_with_status(0, &tmp_file_path);
         }
@@ -0,0 +1,44 @@
+/*
+ * Prints a generated input for a day, e.g. `cargo gen 11 --size 1000 --seed 7`.
+ * Days opt in with a `generate` function marked in `src/days.rs`, see
+ * `helpers::generate`.
+ */
+use advent_of_code::days;
+use advent_of_code::helpers::generate::{Rng, DEFAULT_SIZE};
+use std::process;
+
+struct Args {
+    day: u8,
+    size: usize,
+    seed: u64,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        size: args.opt_value_from_str("--size")?.unwrap_or(DEFAULT_SIZE),
+        seed: args.opt_value_from_str("--seed")?.unwrap_or(0),
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!("usage: `cargo gen <day> [--size N] [--seed S]`");
+            process::exit(1);
+        }
+    };
+
+    let generate = days::ALL
+        .iter()
+        .find(|day| day.day == args.day)
+        .and_then(|day| day.generate);
+    let Some(generate) = generate else {
+        eprintln!("Day {:02} has no input generator.", args.day);
+        process::exit(1);
+    };
+    print!("{}", generate(args.size, &mut Rng::new(args.seed)));
+}
@@ -0,0 +1,110 @@
+/*
+ * Encrypts or decrypts all of `src/inputs`, see `src/encrypt.rs`.
+ *
+ * `cargo inputs keygen` writes a new key to `.aoc-key`; share it with teammates
+ * and CI (as `AOC_KEY`) out of band. `cargo inputs encrypt` writes `NN.txt.enc`
+ * next to every `NN.txt`, and `cargo inputs decrypt` does the reverse. Inputs
+ * whose encrypted file is up to date are skipped, so it doesn't change in git.
+ * Profile directories (`src/inputs/<profile>/`) are included.
+ */
+use advent_of_code::encrypt::{self, Key, EXTENSION, KEY_FILE};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process;
+
+const INPUTS: &str = "src/inputs";
+
+/// Files in `src/inputs` and its profile directories with the given extension.
+fn files(extension: &str) -> Vec<PathBuf> {
+    let entries = |dir: &Path| {
+        fs::read_dir(dir)
+            .map(|dir| {
+                dir.filter_map(|entry| Some(entry.ok()?.path()))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+    };
+    let mut paths = entries(Path::new(INPUTS))
+        .into_iter()
+        .flat_map(|path| {
+            if path.is_dir() {
+                entries(&path)
+            } else {
+                vec![path]
+            }
+        })
+        .filter(|path| path.extension().is_some_and(|ext| ext == extension))
+        .filter(|path| {
+            // inputs only, not a profile's `answers.txt`.
+            let stem = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("");
+            stem.split('.')
+                .next()
+                .is_some_and(|day| day.parse::<u8>().is_ok())
+        })
+        .collect::<Vec<_>>();
+    paths.sort();
+    paths
+}
+
+fn encrypt_all(key: &Key) -> Result<(), String> {
+    for path in files("txt") {
+        let input = fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let encrypted_path = encrypt::encrypted_path(&path);
+        let unchanged = fs::read(&encrypted_path)
+            .ok()
+            .and_then(|data| encrypt::decrypt(key, &data).ok())
+            .is_some_and(|existing| existing == input);
+        if unchanged {
+            continue;
+        }
+        fs::write(&encrypted_path, encrypt::encrypt(key, &input))
+            .map_err(|e| format!("{}: {}", encrypted_path.display(), e))?;
+        println!("Encrypted {}", encrypted_path.display());
+    }
+    Ok(())
+}
+
+fn decrypt_all(key: &Key) -> Result<(), String> {
+    for path in files(EXTENSION) {
+        let data = fs::read(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let input =
+            encrypt::decrypt(key, &data).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let plain_path = path.with_extension("");
+        fs::write(&plain_path, input).map_err(|e| format!("{}: {}", plain_path.display(), e))?;
+        println!("Decrypted {}", plain_path.display());
+    }
+    Ok(())
+}
+
+fn keygen() -> Result<(), String> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(KEY_FILE)
+        .map_err(|e| format!("{}: {}", KEY_FILE, e))?;
+    writeln!(file, "{}", encrypt::generate_key()).map_err(|e| e.to_string())?;
+    println!("Wrote a new key to {}", Path::new(KEY_FILE).display());
+    Ok(())
+}
+
+fn main() {
+    let mut args = pico_args::Arguments::from_env();
+    let command: Option<String> = args.opt_free_from_str().unwrap_or(None);
+    let result = match command.as_deref() {
+        Some("keygen") => keygen(),
+        Some("encrypt") => encrypt::key().and_then(|key| encrypt_all(&key)),
+        Some("decrypt") => encrypt::key().and_then(|key| decrypt_all(&key)),
+        _ => {
+            eprintln!("usage: `cargo inputs <keygen|encrypt|decrypt>`");
+            process::exit(1);
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("Failed: {}", e);
+        process::exit(1);
+    }
+}
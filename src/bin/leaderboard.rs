@@ -0,0 +1,161 @@
+/*
+ * Shows a private leaderboard, e.g. `cargo leaderboard --id 12345 --sort delta`.
+ *
+ * The JSON is cached in `leaderboard/<id>.json` and only downloaded with `--refresh`,
+ * at most once per 15 minutes as adventofcode.com asks. Downloading uses the session
+ * cookie from `AOC_SESSION` or aoc-cli's `~/.adventofcode.session`, and a User-Agent
+ * with `AOC_CONTACT` (e.g. an email address) in it if set. A JSON file can also be
+ * given directly: `cargo leaderboard path/to/leaderboard.json`.
+ *
+ * Options: `--day N` for the times to each star of a day, `--sort score|stars|name|delta`.
+ */
+use advent_of_code::leaderboard::{Leaderboard, Sort};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::time::Duration;
+use std::{env, fs};
+
+const CACHE_DIR: &str = "leaderboard";
+const REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+struct Args {
+    id: Option<u64>,
+    refresh: bool,
+    day: Option<u8>,
+    sort: Sort,
+    file: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        id: args.opt_value_from_str("--id")?,
+        refresh: args.contains("--refresh"),
+        day: args.opt_value_from_str("--day")?,
+        sort: args.opt_value_from_str("--sort")?.unwrap_or(Sort::Score),
+        file: args.opt_free_from_str()?,
+    })
+}
+
+fn session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Some(session);
+    }
+    let home = env::var("HOME").ok()?;
+    let session = fs::read_to_string(Path::new(&home).join(".adventofcode.session")).ok()?;
+    Some(session.trim().to_string())
+}
+
+/// Identifies this tool to adventofcode.com, as its automation guidelines ask, with
+/// `AOC_CONTACT` (e.g. an email address) added if set.
+fn user_agent() -> String {
+    let agent = concat!(
+        env!("CARGO_PKG_NAME"),
+        "/",
+        env!("CARGO_PKG_VERSION"),
+        " by ",
+        env!("CARGO_PKG_AUTHORS")
+    );
+    match env::var("AOC_CONTACT") {
+        Ok(contact) if !contact.is_empty() => format!("{} ({})", agent, contact),
+        _ => agent.to_string(),
+    }
+}
+
+fn is_fresh(path: &Path) -> bool {
+    fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age < REFRESH_INTERVAL)
+}
+
+fn download(id: u64, path: &Path) -> Result<(), String> {
+    let session = session().ok_or("no session cookie in AOC_SESSION or ~/.adventofcode.session")?;
+    let year = env::var("YEAR").map_err(|_| "YEAR is not set")?;
+    let url = format!(
+        "https://adventofcode.com/{}/leaderboard/private/view/{}.json",
+        year, id
+    );
+    println!("Downloading {}", url);
+    // the cookie goes through stdin so it doesn't show up in the process list.
+    let mut curl = Command::new("curl")
+        .args(["--silent", "--fail", "--config", "-", "--user-agent"])
+        .arg(user_agent())
+        .arg(&url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to spawn curl: {}", e))?;
+    let config = format!("cookie = \"session={}\"\n", session.escape_default());
+    curl.stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(config.as_bytes())
+        .map_err(|e| format!("failed to pass the session to curl: {}", e))?;
+    let output = curl
+        .wait_with_output()
+        .map_err(|e| format!("failed to run curl: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("download failed with {}", output.status));
+    }
+    let json = String::from_utf8_lossy(&output.stdout);
+    // an expired session gets redirected to a login page instead.
+    Leaderboard::parse(&json).map_err(|e| format!("not a leaderboard: {}", e))?;
+    fs::create_dir_all(CACHE_DIR)
+        .and_then(|_| fs::write(path, json.as_bytes()))
+        .map_err(|e| format!("failed to cache {}: {}", path.display(), e))
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            eprintln!(
+                "usage: `cargo leaderboard [FILE | --id ID [--refresh]] [--day N] [--sort score|stars|name|delta]`"
+            );
+            process::exit(1);
+        }
+    };
+
+    let path = match (args.file, args.id) {
+        (Some(file), _) => file,
+        (None, Some(id)) => {
+            let path = Path::new(CACHE_DIR).join(format!("{}.json", id));
+            if args.refresh && is_fresh(&path) {
+                println!(
+                    "{} is less than 15 minutes old, not refreshing.",
+                    path.display()
+                );
+            } else if args.refresh || !path.exists() {
+                if let Err(e) = download(id, &path) {
+                    eprintln!("Failed to download leaderboard {}: {}", id, e);
+                    process::exit(1);
+                }
+            }
+            path
+        }
+        (None, None) => {
+            eprintln!("Need a leaderboard JSON file or `--id`.");
+            process::exit(1);
+        }
+    };
+
+    let leaderboard = match fs::read_to_string(&path)
+        .map_err(|e| e.to_string())
+        .and_then(|json| Leaderboard::parse(&json).map_err(|e| e.to_string()))
+    {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", path.display(), e);
+            process::exit(1);
+        }
+    };
+
+    match args.day {
+        Some(day) => print!("{}", leaderboard.render_day(day, args.sort)),
+        None => print!("{}", leaderboard.render(args.sort)),
+    }
+}
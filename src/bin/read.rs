@@ -0,0 +1,53 @@
+/*
+ * Prints a day's puzzle description from `src/puzzles/NN.md`, e.g. `cargo read 7`.
+ * `cargo download` saves it; `cargo download 7 --puzzle` adds part two once it's unlocked.
+ */
+use advent_of_code::markdown;
+use std::{fs, process};
+
+const DEFAULT_WIDTH: usize = 80;
+
+struct Args {
+    day: u8,
+    width: usize,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        width: args.opt_value_from_str("--width")?.unwrap_or(DEFAULT_WIDTH),
+        day: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo read 7 [--width 80]`");
+            process::exit(1);
+        }
+    };
+
+    let day_padded = format!("{:02}", args.day);
+    let puzzle_path = format!("src/puzzles/{}.md", day_padded);
+    let puzzle = match fs::read_to_string(&puzzle_path) {
+        Ok(puzzle) => puzzle,
+        Err(_) => {
+            eprintln!(
+                "No puzzle description in \"{}\". Type `cargo download {}` to fetch it.",
+                puzzle_path, day_padded
+            );
+            process::exit(1);
+        }
+    };
+
+    print!("{}", markdown::render(&puzzle, args.width));
+    if !puzzle.contains("--- Part Two ---") {
+        println!("---");
+        println!(
+            "🎄 Part two isn't downloaded yet. Once unlocked, type `cargo download {} --puzzle`.",
+            day_padded
+        );
+    }
+}
@@ -0,0 +1,97 @@
+/*
+ * Regenerates the progress table in `README.md`, between the marker comments.
+ * Stars come from the submission ledger, or from a part giving the expected answer on
+ * the day's example.
+ * Runtimes are criterion's mean estimates from the latest `cargo bench-days` run.
+ */
+use advent_of_code::runner::{self, Day};
+use advent_of_code::{days, submit};
+use std::path::Path;
+use std::time::Duration;
+use std::{env, fs, process};
+
+const README: &str = "README.md";
+const START: &str = "<!--- progress:start --->";
+const END: &str = "<!--- progress:end --->";
+
+/// Whether `part` gives the day's expected answer on its example.
+fn solves_example(day: &Day, part: u8) -> bool {
+    let part = part as usize - 1;
+    day.answers[part].is_some_and(|expected| runner::run(day.parts[part], day.example).is(expected))
+}
+
+/// The mean runtime of the latest benchmark, preferring the real input.
+fn runtime(day: u8, part: u8) -> Option<Duration> {
+    let solver = if part == 1 { "part_one" } else { "part_two" };
+    let dir = Path::new("target/criterion")
+        .join(format!("day{:02}", day))
+        .join(solver);
+    let estimates = ["inputs", "examples"]
+        .iter()
+        .find_map(|folder| fs::read_to_string(dir.join(folder).join("new/estimates.json")).ok())?;
+    let estimates: serde_json::Value = serde_json::from_str(&estimates).ok()?;
+    let nanos = estimates["mean"]["point_estimate"].as_f64()?;
+    Some(Duration::from_nanos(nanos as u64))
+}
+
+fn table(year: &str) -> String {
+    let ledger = submit::ledger();
+    let mut total = Duration::ZERO;
+    let mut stars = 0;
+    let mut out = String::from("| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n");
+    for day in days::ALL {
+        let cells = [1, 2].map(|part| {
+            let star = ledger.contains_key(&(day.day, part)) || solves_example(day, part);
+            stars += star as u32;
+            let time = runtime(day.day, part);
+            total += time.unwrap_or_default();
+            match (star, time) {
+                (true, Some(time)) => format!("⭐ `{:.2?}`", time),
+                (true, None) => "⭐".to_string(),
+                (false, _) => "-".to_string(),
+            }
+        });
+        out += &format!(
+            "| [Day {}](https://adventofcode.com/{}/day/{}) | {} | {} |\n",
+            day.day, year, day.day, cells[0], cells[1]
+        );
+    }
+    out += &format!("\n**Stars:** {} ⭐", stars);
+    if total > Duration::ZERO {
+        out += &format!(" · **Total runtime:** `{:.2?}`", total);
+    }
+    out.push('\n');
+    out
+}
+
+fn main() {
+    let year = env::var("YEAR").unwrap_or_else(|_| "2023".to_string());
+    let readme = match fs::read_to_string(README) {
+        Ok(readme) => readme,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", README, e);
+            process::exit(1);
+        }
+    };
+    let (Some(start), Some(end)) = (readme.find(START), readme.find(END)) else {
+        eprintln!("{} has no `{}` and `{}` markers.", README, START, END);
+        process::exit(1);
+    };
+    if end < start {
+        eprintln!("{} has its markers in the wrong order.", README);
+        process::exit(1);
+    }
+
+    let updated = format!(
+        "{}{}\n\n{}\n{}",
+        &readme[..start],
+        START,
+        table(&year),
+        &readme[end..]
+    );
+    if let Err(e) = fs::write(README, updated) {
+        eprintln!("Failed to write {}: {}", README, e);
+        process::exit(1);
+    }
+    println!("Updated the progress table in {}.", README);
+}
@@ -3,55 +3,25 @@
  * There is no need to edit this file unless you want to change template functionality.
  */
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r#"pub fn part_one(input: &str) -> Option<u32> {
+const MODULE_TEMPLATE: &str = r#"crate::example!("DAY_PADDED", _, _);
+
+pub fn part_one(input: &str) -> Option<u32> {
     None
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
     None
 }
+"#;
 
-#[cfg(feature = "solve")]
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
-#[cfg(feature = "submit1")]
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::submit::submit(DAY, 1, part_one, input);
-}
-
-#[cfg(feature = "submit2")]
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::submit::submit(DAY, 2, part_two, input);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
-    }
+const BIN_TEMPLATE: &str = "advent_of_code::main!(DAY, MODULE);\n";
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
-    }
-}
-"#;
+const DAYS_PATH: &str = "src/days.rs";
 
 fn parse_args() -> Result<u8, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
@@ -66,7 +36,60 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Adds `day` to the `days!` list in `src/days.rs`, keeping it sorted.
+fn register(day: u8) -> Result<(), String> {
+    let source = fs::read_to_string(DAYS_PATH).map_err(|e| e.to_string())?;
+    let start = source.find("\ndays! {\n").ok_or("no `days!` list")? + "\ndays! {\n".len();
+    let end = start
+        + source[start..]
+            .find("}\n")
+            .ok_or("unterminated `days!` list")?;
+    let mut entries = source[start..end]
+        .lines()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    entries.push(format!("    {} => day{:02},", day, day));
+    entries.sort_by_key(|entry| {
+        entry
+            .split("=>")
+            .next()
+            .and_then(|n| n.trim().parse::<u8>().ok())
+    });
+    entries.dedup();
+    let updated = format!(
+        "{}{}\n{}",
+        &source[..start],
+        entries.join("\n"),
+        &source[end..]
+    );
+    fs::write(DAYS_PATH, updated).map_err(|e| e.to_string())
+}
+
+fn write_new(path: &str, contents: &str, what: &str) {
+    let mut file = match safe_create_file(path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create {} file: {}", what, e);
+            process::exit(1);
+        }
+    };
+
+    match file.write_all(contents.as_bytes()) {
+        Ok(_) => {
+            println!("Created {} file \"{}\"", what, path);
+        }
+        Err(e) => {
+            eprintln!("Failed to write {} contents: {}", what, e);
+            process::exit(1);
+        }
+    }
 }
 
 fn main() {
@@ -82,22 +105,38 @@ fn main() {
 
     let input_path = format!("src/inputs/{}.txt", day_padded);
     let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let module_path = format!("src/days/day{}.rs", day_padded);
+    let bin_path = format!("src/bin/{}.rs", day_padded);
+    let module = format!("day{}", day_padded);
+
+    write_new(
+        &module_path,
+        &MODULE_TEMPLATE.replace("DAY_PADDED", &day_padded),
+        "module",
+    );
+    write_new(
+        &bin_path,
+        &BIN_TEMPLATE
+            .replace("DAY", &day.to_string())
+            .replace("MODULE", &module),
+        "binary",
+    );
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    match register(day) {
+        Ok(()) => println!("Added {} to \"{}\"", module, DAYS_PATH),
         Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+            eprintln!("Failed to add {} to {}: {}", module, DAYS_PATH, e);
             process::exit(1);
         }
-    };
+    }
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    // the module embeds the example, so it must exist before the first build.
+    match create_file(&example_path) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created empty example file \"{}\"", &example_path);
         }
         Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+            eprintln!("Failed to create example file: {}", e);
             process::exit(1);
         }
     }
@@ -112,16 +151,6 @@ fn main() {
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
-        }
-    }
-
     println!("---");
     println!(
         "🎄 Type `cargo solve {}` to run your solution.",
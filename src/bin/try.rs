@@ -0,0 +1,32 @@
+/*
+ * Runs one day's tests, e.g. `cargo try 8`. The tests live in the library, so this
+ * filters them on the `days::dayNN::` module path, which a substring like `8`
+ * can't do without also matching day 18. Arguments after the day go to the test
+ * harness, e.g. `cargo try 8 -- --nocapture`.
+ */
+use std::process::{self, Command};
+
+fn main() {
+    let mut args = pico_args::Arguments::from_env();
+    let day: u8 = match args.free_from_str() {
+        Ok(day) => day,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo try 8`");
+            process::exit(1);
+        }
+    };
+
+    let filter = format!("days::day{:02}::", day);
+    let mut cargo = Command::new("cargo");
+    cargo.args(["test", "--lib"]);
+    if cfg!(feature = "parallel") {
+        cargo.args(["--features", "parallel"]);
+    }
+    let harness_args = args.finish().into_iter().skip_while(|arg| arg == "--");
+    cargo.args(["--", &filter]).args(harness_args);
+    let status = cargo.status().unwrap_or_else(|e| {
+        eprintln!("Failed to run cargo test: {}", e);
+        process::exit(1);
+    });
+    process::exit(status.code().unwrap_or(1));
+}
@@ -0,0 +1,39 @@
+/*
+ * The solutions, one module per day. `src/bin/NN.rs` runs a day from the command line
+ * (see [`main!`](crate::main)), and [`ALL`] lists them for tools that run several days.
+ * `cargo scaffold` adds new days to the list below; `(generate)` marks days with an
+ * input generator, see `helpers::generate`.
+ */
+use crate::runner::Day;
+
+macro_rules! days {
+    ($($day:literal => $module:ident $(($generate:ident))?,)*) => {
+        $(pub mod $module;)*
+
+        /// Every day with a solution, in order.
+        pub const ALL: &[Day] = &[$(crate::day!($day, $module $(, $generate)?)),*];
+    };
+}
+
+days! {
+    1 => day01,
+    2 => day02,
+    3 => day03 (generate),
+    4 => day04,
+    5 => day05,
+    6 => day06,
+    7 => day07,
+    8 => day08,
+    9 => day09 (generate),
+    10 => day10,
+    11 => day11 (generate),
+    12 => day12 (generate),
+    13 => day13,
+    14 => day14 (generate),
+    15 => day15,
+    16 => day16 (generate),
+    17 => day17,
+    18 => day18,
+    19 => day19,
+    20 => day20,
+}
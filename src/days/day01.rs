@@ -0,0 +1,74 @@
+use crate::helpers::bytes;
+
+crate::example!("01", 209, 281);
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut result = 0;
+    for line in bytes::lines(input.as_bytes()) {
+        if let Some(first) = line.iter().find(|c| c.is_ascii_digit()) {
+            result += (first - b'0') as u32 * 10;
+        }
+        if let Some(last) = line.iter().rev().find(|c| c.is_ascii_digit()) {
+            result += (last - b'0') as u32;
+        }
+    }
+
+    Some(result)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let map = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    let lines = input.lines();
+    let mut result = 0;
+    for line in lines {
+        let mut firstnumber = 0;
+        let mut firstindex = line.len();
+        for (i, c) in line.chars().enumerate() {
+            if let Some(num) = c.to_digit(10) {
+                firstnumber = num;
+                firstindex = i;
+                break;
+            }
+        }
+        if firstindex >= 3 {
+            for (num, number_name) in map.iter().enumerate() {
+                let i = line.find(number_name);
+                if let Some(i) = i {
+                    if i < firstindex {
+                        firstindex = i;
+                        firstnumber = (num + 1) as u32;
+                    }
+                }
+            }
+        }
+        result += firstnumber * 10;
+
+        //reverse ordering now
+
+        let mut lastnumber = 0;
+        let mut lastindex = 0;
+        for (i, c) in line.chars().rev().enumerate() {
+            if let Some(num) = c.to_digit(10) {
+                lastnumber = num;
+                lastindex = line.len() - 1 - i;
+                break;
+            }
+        }
+        if lastindex + 3 < line.len() {
+            for (num, number_name) in map.iter().enumerate() {
+                let i = line.rfind(number_name);
+                if let Some(i) = i {
+                    if i > lastindex {
+                        lastindex = i;
+                        lastnumber = (num + 1) as u32;
+                    }
+                }
+            }
+        }
+        result += lastnumber;
+    }
+
+    Some(result)
+}
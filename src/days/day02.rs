@@ -0,0 +1,66 @@
+use crate::helpers::parse::{parse_lines, ParseError};
+
+crate::example!("02", 8, 2286);
+
+/// The most cubes of each color shown at once, per game.
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Result<Vec<[u32; 3]>, ParseError> {
+    let map = ["red", "green", "blue"];
+    parse_lines(input, |line| {
+        let (_, draws) = crate::scan!(line, "Game {}: {}" => u32, &str)?;
+        let mut result = [0; 3];
+        // the draws run to the end of the line; `start` is each piece's 0-based column.
+        let mut start = line.len() - draws.len();
+        for cubes in draws.split([',', ';']) {
+            let trimmed = cubes.trim();
+            let offset = start + cubes.len() - cubes.trim_start().len();
+            start += cubes.len() + 1;
+            let (num, color) =
+                crate::scan!(trimmed, "{} {}" => u32, &str).map_err(|e| e.right(offset))?;
+            let i = map.iter().position(|&c| c == color).ok_or_else(|| {
+                let column = offset + trimmed.len() - color.len() + 1;
+                ParseError::new(column, format!("unknown color `{}`", color))
+            })?;
+            result[i] = std::cmp::max(result[i], num);
+        }
+        Ok(result)
+    })
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let avail = [12, 13, 14];
+    let mut id_sum = 0;
+    'outer: for (id, result) in parse(input).ok()?.into_iter().enumerate() {
+        for (num_avail, num_req) in avail.iter().zip(result.iter()) {
+            if num_req > num_avail {
+                continue 'outer;
+            }
+        }
+        id_sum += (id + 1) as u32;
+    }
+    Some(id_sum)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let games = parse(input).ok()?;
+    Some(
+        games
+            .iter()
+            .map(|result| result.iter().product::<u32>())
+            .sum(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_columns() {
+        crate::helpers::logging::init_test();
+        let err = parse("Game 1: 3 blue, 4 pink").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(1), 19));
+        let err = parse("Game 1: 3 blue; x red").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(1), 17));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::helpers::bytes;
+
+crate::example!("03", 4361, 467835);
+
+pub enum SchematicPart {
+    Number((u32, u32)),
+    Symbol(char),
+    Empty,
+}
+
+/// `None` for numbers that don't fit a `u32` or rows of different lengths.
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse_to_matrix(input: &str) -> Option<(u32, Vec<Vec<SchematicPart>>)> {
+    let mut matrix = Vec::new();
+    let mut id = 0;
+    let mut last_idx = (0, 0);
+    for (linenum, line) in bytes::lines(input.as_bytes()).enumerate() {
+        let mut row = Vec::new();
+        for (i, &c) in line.iter().enumerate() {
+            match c {
+                b'.' => row.push(SchematicPart::Empty),
+                b'0'..=b'9' => {
+                    //scan left and right for full number
+                    let start = line[..i]
+                        .iter()
+                        .rposition(|c| !c.is_ascii_digit())
+                        .map_or(0, |j| j + 1);
+                    let end = line[i..]
+                        .iter()
+                        .position(|c| !c.is_ascii_digit())
+                        .map_or(line.len(), |j| i + j);
+                    if last_idx != (linenum, start) {
+                        id += 1;
+                        last_idx = (linenum, start);
+                    }
+                    row.push(SchematicPart::Number((id, bytes::int(&line[start..end])?)));
+                }
+                _ => row.push(SchematicPart::Symbol(c as char)),
+            }
+        }
+        matrix.push(row);
+    }
+    if matrix.iter().any(|row| row.len() != matrix[0].len()) {
+        return None;
+    }
+    Some((id + 1, matrix))
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (max_id, matrix) = parse_to_matrix(input)?;
+    let mut nums = vec![0; max_id as usize];
+    for (x, row) in matrix.iter().enumerate() {
+        for (y, part) in row.iter().enumerate() {
+            if matches!(part, SchematicPart::Symbol(_)) {
+                //search in a 3x3 grid around the symbol for numbers
+                for i in -1..=1 {
+                    for j in -1..=1 {
+                        if x as i32 + i < 0
+                            || y as i32 + j < 0
+                            || x as i32 + i >= matrix.len() as i32
+                            || y as i32 + j >= matrix[x].len() as i32
+                        {
+                            continue;
+                        }
+                        if let SchematicPart::Number((id, num)) =
+                            matrix[(x as i32 + i) as usize][(y as i32 + j) as usize]
+                        {
+                            nums[id as usize] = num;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    nums.iter().try_fold(0u32, |sum, &num| sum.checked_add(num))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let (_, matrix) = parse_to_matrix(input)?;
+    let mut result: u32 = 0;
+    for (x, row) in matrix.iter().enumerate() {
+        for (y, part) in row.iter().enumerate() {
+            if matches!(part, SchematicPart::Symbol('*')) {
+                //search in a 3x3 grid around the symbol for numbers now finding two gear numbers
+                let mut part_numbers = Vec::new();
+                let mut lastid = None;
+                for i in -1..=1 {
+                    for j in -1..=1 {
+                        if x as i32 + i < 0
+                            || y as i32 + j < 0
+                            || x as i32 + i >= matrix.len() as i32
+                            || y as i32 + j >= matrix[x].len() as i32
+                        {
+                            continue;
+                        }
+                        if let SchematicPart::Number((id, num)) =
+                            matrix[(x as i32 + i) as usize][(y as i32 + j) as usize]
+                        {
+                            if lastid.is_none() || lastid.unwrap() != id {
+                                part_numbers.push(num);
+                                lastid = Some(id);
+                            }
+                        }
+                    }
+                }
+                if part_numbers.len() == 2 {
+                    let ratio = part_numbers[0].checked_mul(part_numbers[1])?;
+                    result = result.checked_add(ratio)?;
+                }
+            }
+        }
+    }
+    Some(result)
+}
+
+/// A `size`×`size` schematic of 1-3 digit numbers and symbols.
+pub fn generate(size: usize, rng: &mut crate::helpers::generate::Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        while line.len() < size {
+            let room = size - line.len();
+            if rng.chance(0.12) {
+                let digits = (rng.range(1..4) as usize).min(room);
+                let number = rng.range(10_i64.pow(digits as u32 - 1)..10_i64.pow(digits as u32));
+                line += &number.to_string();
+                if line.len() < size {
+                    line.push('.');
+                }
+            } else if rng.chance(0.08) {
+                line.push(*rng.choose(&['*', '#', '+', '$', '/', '=', '&', '%', '@', '-']));
+            } else {
+                line.push('.');
+            }
+        }
+        out += &line;
+        out.push('\n');
+    }
+    out
+}
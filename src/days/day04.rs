@@ -0,0 +1,72 @@
+use crate::helpers::parse::{self, parse_lines, ParseError};
+use ahash::HashSet;
+
+crate::example!("04", 13, 30);
+
+/// A card's winning numbers and the numbers on it.
+pub type Card = (HashSet<u32>, Vec<u32>);
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+    parse_lines(input, |line| {
+        let (_, winning, nums) =
+            crate::scan!(line, "Card {}: {} | {}" => &str, &str, &str)?;
+        Ok((
+            parse::ints(winning)?.into_iter().collect(),
+            parse::ints(nums)?,
+        ))
+    })
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let games = parse(input).ok()?;
+    let mut score: u32 = 0;
+    for (winning, nums) in games {
+        let mut num_matches = 0;
+        for num in nums {
+            if winning.contains(&num) {
+                num_matches += 1;
+            }
+        }
+        if num_matches > 0 {
+            score = score.checked_add(1u32.checked_shl(num_matches - 1)?)?;
+        }
+    }
+    Some(score)
+}
+
+pub struct Game {
+    num: u32,
+    amount: u32,
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let games = parse(input).ok()?;
+    let mut queue = Vec::with_capacity(games.len());
+    for i in 0..games.len() {
+        queue.push(Game {
+            num: i as u32,
+            amount: 1,
+        });
+    }
+
+    for i in 0..queue.len() {
+        let game_num = queue[i].num;
+        let game_amount = queue[i].amount;
+        let mut num_matches = 0;
+
+        for num in &games[game_num as usize].1 {
+            if games[game_num as usize].0.contains(num) {
+                num_matches += 1;
+            }
+        }
+        for j in 0..num_matches {
+            let won = queue.get_mut(i + j + 1)?;
+            won.amount = won.amount.checked_add(game_amount)?;
+        }
+    }
+
+    queue
+        .into_iter()
+        .try_fold(0u32, |sum, game| sum.checked_add(game.amount))
+}
@@ -0,0 +1,95 @@
+use crate::helpers::interval::{Interval, IntervalSet};
+use crate::helpers::parse::{self, parse_lines, ParseError};
+
+crate::example!("05", 35, 46);
+
+/// One line of a map: the values in `source` move by `offset`.
+pub struct Mapping {
+    pub source: Interval<i64>,
+    pub offset: i64,
+}
+
+/// A whole map, e.g. seed-to-soil. Values no mapping covers stay as they are.
+pub struct MappingSet {
+    pub mappings: Vec<Mapping>,
+}
+
+/// `len` values from `start`, if they don't overflow.
+fn checked_interval(start: i64, len: i64) -> Option<Interval<i64>> {
+    (len >= 0).then_some(Interval::new(start, start.checked_add(len)?))
+}
+
+impl Mapping {
+    /// The mapping of a `destination source range` line, `None` if it overflows.
+    pub fn new(source: i64, destination: i64, range: i64) -> Option<Self> {
+        checked_interval(destination, range)?;
+        Some(Self {
+            source: checked_interval(source, range)?,
+            offset: destination.checked_sub(source)?,
+        })
+    }
+}
+
+impl MappingSet {
+    /// Where every value in `sources` ends up.
+    pub fn get_destinations(&self, sources: &IntervalSet<i64>) -> IntervalSet<i64> {
+        let mut unmapped = sources.clone();
+        let mut destinations = IntervalSet::new();
+        for mapping in &self.mappings {
+            let source = IntervalSet::from(mapping.source);
+            destinations =
+                destinations.union(&unmapped.intersection(&source).shift(mapping.offset));
+            unmapped = unmapped.difference(&source);
+        }
+        destinations.union(&unmapped)
+    }
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Result<(Vec<i64>, Vec<MappingSet>), ParseError> {
+    let mut blocks = parse::blocks(input);
+    let (_, seeds) = blocks.next().unwrap_or_default();
+    let seeds = parse::ints(seeds).map_err(|e| e.at_line(1))?;
+    let mut mapping_sets = Vec::new();
+    for (offset, chunk) in blocks {
+        let (_, lines) = chunk.split_once('\n').unwrap_or((chunk, ""));
+        let mappings = parse_lines(lines, |line| match parse::ints(line)?.as_slice() {
+            &[destination, source, range] => Mapping::new(source, destination, range)
+                .ok_or_else(|| ParseError::new(1, "range out of bounds")),
+            _ => Err(ParseError::new(1, "expected three numbers")),
+        })
+        .map_err(|e| e.below(offset + 1))?;
+        mapping_sets.push(MappingSet { mappings });
+    }
+    Ok((seeds, mapping_sets))
+}
+
+fn lowest_location(seeds: IntervalSet<i64>, mapping_sets: &[MappingSet]) -> Option<i64> {
+    mapping_sets
+        .iter()
+        .fold(seeds, |current, mapping_set| {
+            mapping_set.get_destinations(&current)
+        })
+        .min()
+}
+
+pub fn part_one(input: &str) -> Option<i64> {
+    let (seeds, mapping_sets) = parse(input).ok()?;
+    let seeds = seeds
+        .iter()
+        .map(|&seed| checked_interval(seed, 1))
+        .collect::<Option<_>>()?;
+    lowest_location(seeds, &mapping_sets)
+}
+
+pub fn part_two(input: &str) -> Option<i64> {
+    let (seeds, mapping_sets) = parse(input).ok()?;
+    if seeds.len() % 2 != 0 {
+        return None;
+    }
+    let seeds = seeds
+        .chunks(2)
+        .map(|pair| checked_interval(pair[0], pair[1]))
+        .collect::<Option<_>>()?;
+    lowest_location(seeds, &mapping_sets)
+}
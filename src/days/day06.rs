@@ -0,0 +1,48 @@
+use crate::helpers::parse::{self, ParseError};
+
+crate::example!("06", 288, 71503);
+
+/// The time and record distance of each race.
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Result<Vec<[u64; 2]>, ParseError> {
+    let (times, dists) =
+        crate::scan!(input.trim_end(), "Time:{}\nDistance:{}" => &str, &str)?;
+    let (times, dists) = (parse::ints(times)?, parse::ints(dists)?);
+    if times.len() != dists.len() {
+        return Err(ParseError::new(1, "times and distances differ in number").at_line(2));
+    }
+    Ok(times.into_iter().zip(dists).map(|(t, d)| [t, d]).collect())
+}
+
+fn get_combinations(input: &[u64; 2]) -> u32 {
+    let time = input[0] as f64;
+    let dist = input[1] as f64;
+
+    let discriminant = time * time - 4.0 * dist;
+    if discriminant <= 0.0 {
+        return 0;
+    }
+    let first_root = 0.5 * (time - discriminant.sqrt());
+    let second_root = 0.5 * (time + discriminant.sqrt());
+    ((second_root.ceil() - first_root.floor()) as u32).saturating_sub(1)
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    parse(input)
+        .ok()?
+        .iter()
+        .map(get_combinations)
+        .try_fold(1u32, |product, n| product.checked_mul(n))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let parsed = parse(input).ok()?;
+    let mut time = "".to_string();
+    let mut dist = "".to_string();
+    for race in &parsed {
+        time.push_str(&race[0].to_string());
+        dist.push_str(&race[1].to_string());
+    }
+    let real_data = [time.parse().ok()?, dist.parse().ok()?];
+    Some(get_combinations(&real_data))
+}
@@ -0,0 +1,149 @@
+use crate::helpers::explain;
+use crate::helpers::parse::{parse_lines, ParseError};
+
+crate::example!("07", 6440, 5905);
+
+const KINDS: [&str; 7] = [
+    "high card",
+    "one pair",
+    "two pair",
+    "three of a kind",
+    "full house",
+    "four of a kind",
+    "five of a kind",
+];
+
+fn char_to_u8(c: char) -> Option<u8> {
+    match c {
+        '1' => Some(1),
+        '2'..='9' => Some(c as u8 - b'0'),
+        'T' => Some(10),
+        'J' => Some(11),
+        'Q' => Some(12),
+        'K' => Some(13),
+        'A' => Some(14),
+        _ => None,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hand {
+    cards: Vec<u8>,
+}
+
+impl Hand {
+    fn new(cards: &str) -> Result<Self, ParseError> {
+        let cards = cards
+            .chars()
+            .map(char_to_u8)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| ParseError::new(1, "invalid card"))?;
+        if cards.len() != 5 {
+            return Err(ParseError::new(1, "a hand has five cards"));
+        }
+        Ok(Hand { cards })
+    }
+
+    /// The cards as written, with jokers as `J`.
+    fn label(&self) -> String {
+        self.cards
+            .iter()
+            .map(|&card| b"?J23456789TJQKA"[card as usize] as char)
+            .collect()
+    }
+
+    fn get_kind(&self) -> u8 {
+        //five of a kind
+
+        let mut cards = [0; 14];
+        for &card in self.cards.iter() {
+            cards[card as usize - 1] += 1;
+        }
+
+        let mut highest = 0;
+        let mut second_highest = 0;
+        for &card in cards.iter().skip(1) {
+            if card > highest {
+                second_highest = highest;
+                highest = card;
+            } else if card > second_highest {
+                second_highest = card;
+            }
+        }
+
+        let num_jokers = cards[0];
+        highest += num_jokers;
+
+        match (highest, second_highest) {
+            (5, _) => 6,
+            (4, _) => 5,
+            (3, 2) => 4,
+            (3, _) => 3,
+            (2, 2) => 2,
+            (2, _) => 1,
+            _ => 0,
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        //detect hand type
+        let self_kind = self.get_kind();
+        let other_kind = other.get_kind();
+        if self_kind != other_kind {
+            return self_kind.cmp(&other_kind);
+        }
+        //detect first highest card
+        for (self_card, other_card) in self.cards.iter().zip(other.cards.iter()) {
+            if self_card != other_card {
+                return self_card.cmp(other_card);
+            }
+        }
+        std::cmp::Ordering::Equal
+    }
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Result<Vec<(Hand, u32)>, ParseError> {
+    parse_lines(input, |line| {
+        let (hand, bet) = crate::scan!(line, "{} {}" => &str, u32)?;
+        Ok((Hand::new(hand)?, bet))
+    })
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut parsed = parse(input).ok()?;
+    parsed.sort();
+    explain::table(
+        "hands, weakest first",
+        &["hand", "kind", "rank", "bid", "winnings"],
+        || {
+            parsed.iter().zip(1u64..).map(|((hand, bet), rank)| {
+                vec![
+                    hand.label(),
+                    KINDS[hand.get_kind() as usize].to_string(),
+                    rank.to_string(),
+                    bet.to_string(),
+                    (rank * *bet as u64).to_string(),
+                ]
+            })
+        },
+    );
+    parsed
+        .iter()
+        .enumerate()
+        .try_fold(0u32, |sum, (i, (_, bet))| {
+            sum.checked_add((i as u32 + 1).checked_mul(*bet)?)
+        })
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    part_one(input.replace("J", "1").as_str())
+}
@@ -0,0 +1,81 @@
+use crate::helpers::parse::{parse_lines, ParseError};
+use crate::helpers::{cycle, gcd};
+use ahash::HashMap;
+use itertools::Itertools;
+
+crate::example!("08", _, 6);
+
+pub type Network<'a> = HashMap<&'a str, [&'a str; 2]>;
+
+/// The moves as indices into a node's `[left, right]`, and the network, whose
+/// nodes all lead to nodes in it.
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Result<(Vec<usize>, Network<'_>), ParseError> {
+    let (moves, nodes) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::new(1, "expected a blank line").at_line(2))?;
+    let map = parse_lines(nodes, |line| {
+        let (key, left, right) = crate::scan!(line, "{} = ({}, {})" => &str, &str, &str)?;
+        Ok((key, [left, right]))
+    })
+    .map_err(|e| e.below(2))?;
+    let moves = moves
+        .bytes()
+        .map(|c| match c {
+            b'L' => Ok(0),
+            b'R' => Ok(1),
+            _ => Err(ParseError::new(1, format!("invalid move `{}`", c as char)).at_line(1)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if moves.is_empty() {
+        return Err(ParseError::new(1, "no moves").at_line(1));
+    }
+    let map = map.into_iter().collect::<Network>();
+    if let Some(node) = map.values().flatten().find(|node| !map.contains_key(*node)) {
+        return Err(ParseError::new(1, format!("unknown node `{}`", node)));
+    }
+    Ok((moves, map))
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (moves, map) = parse(input).ok()?;
+    let mut state = "AAA";
+    let mut cursor = 0;
+    while state != "ZZZ" {
+        // every (node, move) pair has been visited, so ZZZ is out of reach.
+        if cursor > map.len() * moves.len() {
+            return None;
+        }
+        state = map.get(state)?[moves[cursor % moves.len()]];
+        cursor += 1;
+    }
+    Some(cursor as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let (moves, map) = parse(input).ok()?;
+    let step =
+        |&(state, cursor): &(&str, usize)| (map[state][moves[cursor]], (cursor + 1) % moves.len());
+    let possible_cursors = map
+        .keys()
+        .filter(|key| key.ends_with('A'))
+        .map(|&start| {
+            let cycle = cycle::find_cycle((start, 0), step);
+            let cursors = std::iter::successors(Some((start, 0)), |state| Some(step(state)))
+                .take(cycle.mu + cycle.lambda)
+                .positions(|(state, _)| state.ends_with('Z'))
+                .collect::<Vec<_>>();
+            tracing::debug!(start, ?cycle, ?cursors, "ghost reaches Z");
+            cursors
+        })
+        .collect::<Vec<_>>();
+    // the lowest common multiple of the possible cursors, none of which is 0 as
+    // no start ends with Z.
+    possible_cursors
+        .iter()
+        .flatten()
+        .map(|&cursor| cursor as u64)
+        .try_fold(1u64, |lcm, cursor| {
+            (lcm / gcd(lcm, cursor)).checked_mul(cursor)
+        })
+}
@@ -0,0 +1,59 @@
+use crate::helpers::parse::{self, parse_lines, ParseError};
+use itertools::Itertools;
+
+crate::example!("09", 114, 2);
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    parse_lines(input, parse::ints)
+}
+
+/// The next value, or `None` if it overflows.
+fn extrapolate(input: &[i32]) -> Option<i32> {
+    match input.last() {
+        Some(&last) if input.iter().any(|&x| x != 0) => {
+            let diffs = input
+                .iter()
+                .tuple_windows()
+                .map(|(a, b)| b.checked_sub(*a))
+                .collect::<Option<Vec<_>>>()?;
+            last.checked_add(extrapolate(&diffs)?)
+        }
+        _ => Some(0),
+    }
+}
+
+fn extrapolate_all(input: &[Vec<i32>]) -> Option<i32> {
+    input
+        .iter()
+        .try_fold(0i32, |sum, line| sum.checked_add(extrapolate(line)?))
+}
+
+pub fn part_one(input: &str) -> Option<i32> {
+    extrapolate_all(&parse(input).ok()?)
+}
+
+pub fn part_two(input: &str) -> Option<i32> {
+    let input: Vec<Vec<i32>> = parse(input)
+        .ok()?
+        .iter()
+        .map(|line| line.iter().rev().copied().collect())
+        .collect();
+    extrapolate_all(&input)
+}
+
+/// `size` histories of 21 values of random polynomials of degree at most 4.
+pub fn generate(size: usize, rng: &mut crate::helpers::generate::Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let coefficients = (0..rng.range(1..6))
+            .map(|_| rng.range(-5..6))
+            .collect::<Vec<_>>();
+        let history = (0..21)
+            .map(|x| coefficients.iter().rev().fold(0, |acc, c| acc * x + c))
+            .join(" ");
+        out += &history;
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,272 @@
+use crate::helpers::explain;
+use crate::helpers::polygon::Polygon;
+use crate::helpers::visualize::{self, Canvas, Cell, Rgb};
+
+crate::example!("10", 23, 4);
+
+/// `None` unless the map is a non-empty rectangle with exactly one start.
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Option<(Vec<Vec<char>>, [usize; 2])> {
+    let mut map = Vec::new();
+    let mut starts = Vec::new();
+    for (y, line) in input.lines().enumerate() {
+        let mut row = Vec::new();
+        for (x, c) in line.chars().enumerate() {
+            if c == 'S' {
+                starts.push([x, y]);
+            }
+            row.push(c);
+        }
+        map.push(row);
+    }
+    let width = map.first()?.len();
+    if width == 0 || map.iter().any(|row| row.len() != width) || starts.len() != 1 {
+        return None;
+    }
+    Some((map, starts[0]))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapState {
+    N,
+    NW,
+    NE,
+    E,
+    EN,
+    ES,
+    S,
+    SE,
+    SW,
+    W,
+    WN,
+    WS,
+    Outside,
+}
+
+fn to_char(value: &Option<MapState>) -> char {
+    match value {
+        Some(MapState::N) => '║',
+        Some(MapState::NW) => '╗',
+        Some(MapState::NE) => '╔',
+        Some(MapState::E) => '═',
+        Some(MapState::EN) => '╝',
+        Some(MapState::ES) => '╗',
+        Some(MapState::S) => '║',
+        Some(MapState::SE) => '╚',
+        Some(MapState::SW) => '╝',
+        Some(MapState::W) => '═',
+        Some(MapState::WN) => '╚',
+        Some(MapState::WS) => '╔',
+        Some(MapState::Outside) => 'O',
+        None => ' ',
+    }
+}
+
+fn to_dir(last_dir: &MapState, dir: &MapState) -> Option<MapState> {
+    match (last_dir, dir) {
+        (MapState::N, MapState::S) => Some(MapState::S),
+        (MapState::S, MapState::N) => Some(MapState::N),
+        (MapState::W, MapState::E) => Some(MapState::E),
+        (MapState::E, MapState::W) => Some(MapState::W),
+        (MapState::N, MapState::W) => Some(MapState::SW),
+        (MapState::N, MapState::E) => Some(MapState::SE),
+        (MapState::S, MapState::W) => Some(MapState::NW),
+        (MapState::S, MapState::E) => Some(MapState::NE),
+        (MapState::W, MapState::N) => Some(MapState::EN),
+        (MapState::W, MapState::S) => Some(MapState::ES),
+        (MapState::E, MapState::N) => Some(MapState::WN),
+        (MapState::E, MapState::S) => Some(MapState::WS),
+        _ => None,
+    }
+}
+
+/// The tile next to `pos` in direction `dir`, if it's on the map.
+fn step(pos: [usize; 2], dir: MapState, map: &[Vec<char>]) -> Option<[usize; 2]> {
+    let [x, y] = pos;
+    let next = match dir {
+        MapState::N => [x, y.checked_sub(1)?],
+        MapState::S => [x, y + 1],
+        MapState::W => [x.checked_sub(1)?, y],
+        MapState::E => [x + 1, y],
+        _ => return None,
+    };
+    (next[1] < map.len() && next[0] < map[0].len()).then_some(next)
+}
+
+/// The direction to leave pipe `c` in after entering it heading `dir`.
+fn turn(c: char, dir: MapState) -> Option<MapState> {
+    match (c, dir) {
+        ('|', MapState::S) => Some(MapState::S),
+        ('|', MapState::N) => Some(MapState::N),
+        ('-', MapState::W) => Some(MapState::W),
+        ('-', MapState::E) => Some(MapState::E),
+        ('L', MapState::W) => Some(MapState::N),
+        ('L', MapState::S) => Some(MapState::E),
+        ('J', MapState::S) => Some(MapState::W),
+        ('J', MapState::E) => Some(MapState::N),
+        ('7', MapState::N) => Some(MapState::W),
+        ('7', MapState::E) => Some(MapState::S),
+        ('F', MapState::N) => Some(MapState::E),
+        ('F', MapState::W) => Some(MapState::S),
+        ('S', _) => Some(dir),
+        _ => None,
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (map, start) = parse(input)?;
+    let mut pos;
+    let mut dir;
+    let mut steps = 0;
+
+    //check directions
+    if start[1] > 0
+        && (map[start[1] - 1][start[0]] == '|'
+            || map[start[1] - 1][start[0]] == '7'
+            || map[start[1] - 1][start[0]] == 'F')
+    {
+        pos = [start[0], start[1] - 1];
+        dir = MapState::N;
+    } else if start[1] < map.len() - 1
+        && (map[start[1] + 1][start[0]] == '|'
+            || map[start[1] + 1][start[0]] == 'L'
+            || map[start[1] + 1][start[0]] == 'J')
+    {
+        pos = [start[0], start[1] + 1];
+        dir = MapState::S;
+    } else if start[0] > 0
+        && (map[start[1]][start[0] - 1] == '-'
+            || map[start[1]][start[0] - 1] == 'F'
+            || map[start[1]][start[0] - 1] == 'L')
+    {
+        pos = [start[0] - 1, start[1]];
+        dir = MapState::W;
+    } else if start[0] < map[0].len() - 1
+        && (map[start[1]][start[0] + 1] == '-'
+            || map[start[1]][start[0] + 1] == '7'
+            || map[start[1]][start[0] + 1] == 'J')
+    {
+        pos = [start[0] + 1, start[1]];
+        dir = MapState::E;
+    } else {
+        return None;
+    }
+
+    while pos != start {
+        pos = step(pos, dir, &map)?;
+        dir = turn(map[pos[1]][pos[0]], dir)?;
+        steps += 1;
+    }
+    tracing::debug!(length = steps + 1, "loop closed");
+    Some(steps / 2 + 1)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    //Start by replacing all unneded stuff and only have arrows and dots
+    let (map, start) = parse(input)?;
+    // the traced loop, only drawn for `--visualize` and `--explain`.
+    let mut new_map = (visualize::enabled() || explain::enabled())
+        .then(|| vec![vec![None; map[0].len()]; map.len()]);
+    let mut pos = start;
+    let mut dir = MapState::Outside;
+    let mut last_dir = MapState::Outside;
+
+    if start[1] > 0
+        && (map[start[1] - 1][start[0]] == '|'
+            || map[start[1] - 1][start[0]] == '7'
+            || map[start[1] - 1][start[0]] == 'F')
+    {
+        dir = MapState::N;
+    }
+    if start[1] < map.len() - 1
+        && (map[start[1] + 1][start[0]] == '|'
+            || map[start[1] + 1][start[0]] == 'L'
+            || map[start[1] + 1][start[0]] == 'J')
+    {
+        pos = [start[0], start[1] + 1];
+        last_dir = dir;
+        dir = MapState::S;
+    }
+    if start[0] > 0
+        && (map[start[1]][start[0] - 1] == '-'
+            || map[start[1]][start[0] - 1] == 'F'
+            || map[start[1]][start[0] - 1] == 'L')
+    {
+        pos = [start[0] - 1, start[1]];
+        last_dir = dir;
+        dir = MapState::W;
+    }
+    if start[0] < map[0].len() - 1
+        && (map[start[1]][start[0] + 1] == '-'
+            || map[start[1]][start[0] + 1] == '7'
+            || map[start[1]][start[0] + 1] == 'J')
+    {
+        pos = [start[0] + 1, start[1]];
+        last_dir = dir;
+        dir = MapState::E;
+    }
+    if dir == MapState::Outside || last_dir == MapState::Outside {
+        return None;
+    }
+
+    if let Some(new_map) = &mut new_map {
+        new_map[start[1]][start[0]] = to_dir(&last_dir, &dir);
+    }
+    let mut vertices = vec![[start[0] as i64, start[1] as i64]];
+    while pos != start {
+        vertices.push([pos[0] as i64, pos[1] as i64]);
+        let c = map[pos[1]][pos[0]];
+        last_dir = match &dir {
+            MapState::N => MapState::S,
+            MapState::S => MapState::N,
+            MapState::W => MapState::E,
+            MapState::E => MapState::W,
+            _ => return None,
+        };
+        dir = turn(c, dir)?;
+        if let Some(new_map) = &mut new_map {
+            new_map[pos[1]][pos[0]] = to_dir(&last_dir, &dir);
+        }
+        pos = step(pos, dir, &map)?;
+    }
+
+    //Now the loop is traced; every tile not on it but enclosed by it is inside
+    let inside_amount = Polygon::new(vertices).interior_points() as u32;
+    if let Some(new_map) = &new_map {
+        if visualize::enabled() {
+            let canvas = Canvas::from_grid(new_map, |state| match state {
+                Some(_) => Cell::new(to_char(state)).color(Rgb::GREEN),
+                None => Cell::new(' '),
+            });
+            canvas.print();
+            canvas.save("10", 4).expect("could not save visualization");
+        }
+        explain::text(
+            format_args!("the loop, with the {} tiles inside as I", inside_amount),
+            || {
+                // a tile is inside if an odd number of pipes going north lie left of it.
+                let start_north = start[1] > 0 && matches!(map[start[1] - 1][start[0]], '|' | '7' | 'F');
+                let mut out = String::new();
+                for (y, row) in new_map.iter().enumerate() {
+                    let mut inside = false;
+                    for (x, state) in row.iter().enumerate() {
+                        out.push(match state {
+                            Some(_) => {
+                                inside ^= match map[y][x] {
+                                    'S' => start_north,
+                                    c => matches!(c, '|' | 'L' | 'J'),
+                                };
+                                to_char(state)
+                            }
+                            None if inside => 'I',
+                            None => '.',
+                        });
+                    }
+                    out.push('\n');
+                }
+                out
+            },
+        );
+    }
+    Some(inside_amount)
+}
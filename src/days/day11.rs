@@ -0,0 +1,137 @@
+use crate::helpers::explain;
+use crate::helpers::parse::{self, ParseError};
+
+crate::example!("11", 374, 82000210);
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    parse::grid(input, |c| matches!(c, b'.' | b'#').then_some(c as char))
+}
+
+fn solve(input: &str, dilation: u64) -> Option<u64> {
+    let grid = parse(input).ok()?;
+    let empty_rows = grid
+        .iter()
+        .enumerate()
+        .filter_map(|(y, line)| {
+            if line.iter().all(|&c| c == '.') {
+                Some(y)
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    let empty_columns = (0..grid.first()?.len())
+        .filter(|&x| grid.iter().all(|line| line[x] == '.'))
+        .collect::<Vec<_>>();
+
+    //find # locations
+    let mut locations = Vec::new();
+    for (y, line) in grid.iter().enumerate() {
+        for (x, &c) in line.iter().enumerate() {
+            if c == '#' {
+                let xcount =
+                    empty_columns.iter().filter(|&&xx| xx <= x).count() as u64 * (dilation - 1);
+                let ycount =
+                    empty_rows.iter().filter(|&&yy| yy <= y).count() as u64 * (dilation - 1);
+                locations.push(((x as u64 + xcount) as i64, (y as u64 + ycount) as i64));
+            }
+        }
+    }
+
+    //Generate all pairs
+    let mut pairs = Vec::new();
+    for i in 0..locations.len() {
+        for j in i + 1..locations.len() {
+            pairs.push((locations[i], locations[j]));
+        }
+    }
+
+    explain::table(
+        format_args!("galaxy pairs, with space expanded {} times", dilation),
+        &["a", "b", "distance"],
+        || {
+            pairs.iter().map(|&(a, b)| {
+                vec![
+                    format!("({}, {})", a.0, a.1),
+                    format!("({}, {})", b.0, b.1),
+                    ((a.0 - b.0).abs() + (a.1 - b.1).abs()).to_string(),
+                ]
+            })
+        },
+    );
+
+    //Calculate path lengths
+    Some(
+        pairs
+            .iter()
+            .map(|&(a, b)| (a.0 - b.0).abs() + (a.1 - b.1).abs())
+            .sum::<i64>() as u64,
+    )
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    solve(input, 2)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    solve(input, 1000000)
+}
+
+/// A `size`×`size` image with galaxies on about 2% of the tiles.
+pub fn generate(size: usize, rng: &mut crate::helpers::generate::Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        out.extend((0..size).map(|_| if rng.chance(0.02) { '#' } else { '.' }));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::differential::Differential;
+
+    #[test]
+    fn test_against_reference() {
+        crate::helpers::logging::init_test();
+        Differential::new(generate, reference::part_one, part_one).assert();
+        Differential::new(generate, reference::part_two, part_two).assert();
+    }
+
+    /// Walks every pair's path, weighing each empty row or column it crosses.
+    mod reference {
+        fn solve(input: &str, dilation: u64) -> Option<u64> {
+            let grid = super::parse(input).ok()?;
+            let row_empty = |y: usize| grid[y].iter().all(|&c| c == '.');
+            let column_empty = |x: usize| grid.iter().all(|line| line[x] == '.');
+            let galaxies = (0..grid.len())
+                .flat_map(|y| (0..grid[y].len()).map(move |x| (x, y)))
+                .filter(|&(x, y)| grid[y][x] == '#')
+                .collect::<Vec<_>>();
+
+            let mut total = 0;
+            for (i, &(x1, y1)) in galaxies.iter().enumerate() {
+                for &(x2, y2) in &galaxies[i + 1..] {
+                    let width = |empty: bool| if empty { dilation } else { 1 };
+                    total += (x1.min(x2)..x1.max(x2))
+                        .map(|x| width(column_empty(x)))
+                        .sum::<u64>();
+                    total += (y1.min(y2)..y1.max(y2))
+                        .map(|y| width(row_empty(y)))
+                        .sum::<u64>();
+                }
+            }
+            Some(total)
+        }
+
+        pub fn part_one(input: &str) -> Option<u64> {
+            solve(input, 2)
+        }
+
+        pub fn part_two(input: &str) -> Option<u64> {
+            solve(input, 1000000)
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use crate::helpers::memo::Memo;
+use crate::helpers::parallel::*;
+use crate::helpers::parse::{self, parse_lines, ParseError};
+
+crate::example!("12", 21, 525152);
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Result<Vec<(&str, Vec<u32>)>, ParseError> {
+    parse_lines(input, |line| {
+        let (code, sizes) = crate::scan!(line, "{} {}" => &str, &str)?;
+        Ok((code, parse::ints(sizes)?))
+    })
+}
+
+pub type Cache = Memo<(usize, usize), Option<u64>>;
+
+/// Counts the ways to place `sizes` into `code`, or `None` for invalid springs or
+/// a count that overflows. A sub-problem is identified by the remaining lengths of
+/// both slices, so `memo` must be cleared between lines.
+fn arrangements(code: &[u8], sizes: &[u32], memo: &mut Cache) -> Option<u64> {
+    memo.get_or_insert_with((code.len(), sizes.len()), |memo| {
+        //check that it's even possible and stuff
+        if sizes.is_empty() {
+            //End of the line, baby
+            if code.contains(&b'#') {
+                return Some(0);
+            }
+            return Some(1);
+        }
+        if code.is_empty() {
+            return Some(0);
+        }
+        //scan segment size
+        let max_len = code.iter().position(|&c| c == b'.').unwrap_or(code.len());
+        let fits = sizes[0] <= max_len as u32
+            && code
+                .get(sizes[0] as usize)
+                .map(|&c| c != b'#')
+                .unwrap_or(true);
+        let place = |memo: &mut Cache| {
+            let rest = code.get(sizes[0] as usize + 1..).unwrap_or_default();
+            arrangements(rest, &sizes[1..], memo)
+        };
+
+        match code[0] {
+            b'?' => {
+                //we can try to fit in chunk, or skip
+                let skipped = arrangements(&code[1..], sizes, memo)?;
+                if fits {
+                    place(memo)?.checked_add(skipped)
+                } else {
+                    Some(skipped)
+                }
+            }
+            b'#' => {
+                //we know we have to place something here right now
+                if fits {
+                    place(memo)
+                } else {
+                    Some(0)
+                }
+            }
+            b'.' => arrangements(&code[1..], sizes, memo), //jump one. Will then check if it's valid inside function
+            _ => None,
+        }
+    })
+}
+
+fn count_all(lines: Vec<(String, Vec<u32>)>) -> Option<u64> {
+    lines
+        .into_maybe_par_iter()
+        .map_init(
+            || Memo::named("day 12 arrangements"),
+            |memo, (code, sizes)| {
+                memo.clear();
+                arrangements(code.as_bytes(), &sizes, memo)
+            },
+        )
+        .collect::<Vec<_>>()
+        .into_iter()
+        .try_fold(0u64, |sum, count| sum.checked_add(count?))
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    count_all(
+        parse(input)
+            .ok()?
+            .into_iter()
+            .map(|(code, sizes)| (code.to_string(), sizes))
+            .collect(),
+    )
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    count_all(
+        parse(input)
+            .ok()?
+            .into_iter()
+            .map(|(code, sizes)| ([code; 5].join("?"), sizes.repeat(5)))
+            .collect(),
+    )
+}
+
+/// `size` rows of up to 12 springs, each with one to three damaged groups.
+pub fn generate(size: usize, rng: &mut crate::helpers::generate::Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let code = (0..rng.range(1..13))
+            .map(|_| *rng.choose(&['.', '#', '?', '?']))
+            .collect::<String>();
+        let sizes = (0..rng.range(1..4))
+            .map(|_| rng.range(1..4).to_string())
+            .collect::<Vec<_>>();
+        out += &format!("{} {}\n", code, sizes.join(","));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::differential::Differential;
+
+    #[test]
+    fn test_against_reference() {
+        crate::helpers::logging::init_test();
+        Differential::new(generate, reference::part_one, part_one).assert();
+    }
+
+    /// Tries every assignment of the unknown springs.
+    mod reference {
+        fn groups(code: &[char]) -> Vec<u32> {
+            code.split(|&c| c == '.')
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len() as u32)
+                .collect()
+        }
+
+        fn arrangements(code: &str, sizes: &[u32]) -> u64 {
+            let unknown = code
+                .char_indices()
+                .filter(|&(_, c)| c == '?')
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            (0..1u32 << unknown.len())
+                .filter(|mask| {
+                    let mut code = code.chars().collect::<Vec<_>>();
+                    for (bit, &i) in unknown.iter().enumerate() {
+                        code[i] = if mask >> bit & 1 == 1 { '#' } else { '.' };
+                    }
+                    groups(&code) == sizes
+                })
+                .count() as u64
+        }
+
+        pub fn part_one(input: &str) -> Option<u64> {
+            Some(
+                super::parse(input)
+                    .ok()?
+                    .into_iter()
+                    .map(|(code, sizes)| arrangements(code, &sizes))
+                    .sum(),
+            )
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::helpers::bitgrid::{BitGrid, MAX_WIDTH};
+
+crate::example!("13", 405, 400);
+
+/// Each pattern's rows, and its columns as rows. `None` unless every pattern is a
+/// rectangle of at most 128 by 128.
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Option<Vec<[Vec<u128>; 2]>> {
+    input
+        .split("\n\n")
+        .map(|pattern| {
+            let grid = BitGrid::try_parse(pattern, |c| c == b'#')?;
+            (grid.height() <= MAX_WIDTH)
+                .then(|| [grid.rows().to_vec(), grid.transpose().rows().to_vec()])
+        })
+        .collect()
+}
+
+fn count_diff(top: u128, bottom: u128) -> u32 {
+    (top ^ bottom).count_ones()
+}
+
+fn find_reflections(grids: &[Vec<u128>; 2], goal_diff: u32) -> Option<u32> {
+    for (j, grid) in grids.iter().enumerate() {
+        'outer: for i in 1..grid.len() {
+            let mut diffs = 0;
+            let mut dist = 1;
+            loop {
+                //reach edge
+                if i < dist || i + dist > grid.len() {
+                    if diffs == goal_diff {
+                        return Some(if j == 1 { i } else { i * 100 } as u32);
+                    }
+                    continue 'outer;
+                }
+                diffs += count_diff(grid[i - dist], grid[i + dist - 1]);
+
+                if diffs > goal_diff {
+                    continue 'outer;
+                }
+                dist += 1;
+            }
+        }
+    }
+    None
+}
+
+fn summarize(input: &str, goal_diff: u32) -> Option<u32> {
+    parse(input)?.iter().try_fold(0u32, |sum, grids| {
+        sum.checked_add(find_reflections(grids, goal_diff)?)
+    })
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    summarize(input, 0)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    summarize(input, 1)
+}
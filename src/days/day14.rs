@@ -0,0 +1,184 @@
+use crate::helpers::bitgrid::BitGrid;
+use crate::helpers::cycle;
+use crate::helpers::visualize::{self, Canvas, Cell, Frames, Rgb};
+
+crate::example!("14", 136, 64);
+
+/// The round rocks and the cube rocks.
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Option<(BitGrid, BitGrid)> {
+    Some((
+        BitGrid::try_parse(input, |c| c == b'O')?,
+        BitGrid::try_parse(input, |c| c == b'#')?,
+    ))
+}
+
+/// Rolls every rock as far as it goes. `forward` moves a grid one step in the tilt
+/// direction and `back` one step against it.
+fn tilt(
+    rocks: &BitGrid,
+    walls: &BitGrid,
+    forward: fn(&BitGrid) -> BitGrid,
+    back: fn(&BitGrid) -> BitGrid,
+) -> BitGrid {
+    let mut rocks = rocks.clone();
+    loop {
+        let free = !&(&rocks | walls);
+        let movers = &rocks & &back(&free);
+        if movers.is_empty() {
+            return rocks;
+        }
+        rocks = &(&rocks ^ &movers) | &forward(&movers);
+    }
+}
+
+fn tilt_north(rocks: &BitGrid, walls: &BitGrid) -> BitGrid {
+    tilt(rocks, walls, BitGrid::shift_north, BitGrid::shift_south)
+}
+
+fn spin(rocks: &BitGrid, walls: &BitGrid) -> BitGrid {
+    let rocks = tilt_north(rocks, walls);
+    let rocks = tilt(&rocks, walls, BitGrid::shift_west, BitGrid::shift_east);
+    let rocks = tilt(&rocks, walls, BitGrid::shift_south, BitGrid::shift_north);
+    tilt(&rocks, walls, BitGrid::shift_east, BitGrid::shift_west)
+}
+
+fn grid_load(rocks: &BitGrid) -> u32 {
+    rocks
+        .rows()
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(load_lvl, row)| row.count_ones() * (load_lvl as u32 + 1))
+        .sum()
+}
+
+fn draw(rocks: &BitGrid, walls: &BitGrid) -> Canvas {
+    Canvas::from_fn(rocks.width(), rocks.height(), |x, y| {
+        if rocks.get(x, y) {
+            Cell::new('O').color(Rgb::YELLOW)
+        } else if walls.get(x, y) {
+            Cell::new('#')
+        } else {
+            Cell::new('.')
+        }
+    })
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (rocks, walls) = parse(input)?;
+    Some(grid_load(&tilt_north(&rocks, &walls)))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let (rocks, walls) = parse(input)?;
+    let mut frames = Frames::new("14", 4).expect("could not create frames");
+    let rocks = cycle::nth_state(
+        rocks,
+        |rocks| {
+            if visualize::enabled() {
+                frames
+                    .push(&draw(rocks, &walls))
+                    .expect("could not save frame");
+            }
+            spin(rocks, &walls)
+        },
+        1_000_000_000,
+    );
+    Some(grid_load(&rocks))
+}
+
+/// A `size`×`size` platform with round rocks on about 20% of the tiles and cube
+/// rocks on 10%.
+pub fn generate(size: usize, rng: &mut crate::helpers::generate::Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        out.extend((0..size).map(|_| match rng.below(10) {
+            0 | 1 => 'O',
+            2 => '#',
+            _ => '.',
+        }));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::differential::Differential;
+
+    #[test]
+    fn test_against_reference() {
+        crate::helpers::logging::init_test();
+        Differential::new(generate, reference::part_one, part_one).assert();
+        Differential::new(generate, reference::part_two, part_two).assert();
+    }
+
+    /// Byte grids that tilt north rock by rock and rotate into each direction.
+    mod reference {
+        use crate::helpers::cycle;
+
+        fn parse(input: &str) -> Vec<Vec<u8>> {
+            input.lines().map(|c| c.as_bytes().to_vec()).collect()
+        }
+
+        fn tilt_north(grid: &mut [Vec<u8>]) {
+            for y in 0..grid.len() {
+                for x in 0..grid[0].len() {
+                    if grid[y][x] == b'O' {
+                        //scan north
+                        let mut i = 1;
+                        while i <= y && grid[y - i][x] == b'.' {
+                            i += 1;
+                        }
+                        if i > 1 {
+                            grid[y + 1 - i][x] = b'O';
+                            grid[y][x] = b'.';
+                        }
+                    }
+                }
+            }
+        }
+
+        fn rotate_cw(grid: &[Vec<u8>]) -> Vec<Vec<u8>> {
+            (0..grid[0].len())
+                .map(|y| {
+                    (0..grid.len())
+                        .map(|x| grid[grid.len() - 1 - x][y])
+                        .collect()
+                })
+                .collect()
+        }
+
+        fn spin(grid: &[Vec<u8>]) -> Vec<Vec<u8>> {
+            let mut grid = grid.to_vec();
+            for _ in 0..4 {
+                tilt_north(&mut grid);
+                grid = rotate_cw(&grid);
+            }
+            grid
+        }
+
+        fn grid_load(grid: &[Vec<u8>]) -> u32 {
+            grid.iter()
+                .rev()
+                .enumerate()
+                .map(|(load_lvl, line)| {
+                    line.iter().filter(|&&c| c == b'O').count() as u32 * (load_lvl as u32 + 1)
+                })
+                .sum()
+        }
+
+        pub fn part_one(input: &str) -> Option<u32> {
+            let mut grid = parse(input);
+            tilt_north(&mut grid);
+            Some(grid_load(&grid))
+        }
+
+        pub fn part_two(input: &str) -> Option<u32> {
+            let grid = cycle::nth_state(parse(input), |grid| spin(grid), 1_000_000_000);
+            Some(grid_load(&grid))
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use crate::helpers::bitgrid::{BitGrid, MAX_WIDTH};
+use crate::helpers::parallel::*;
+use crate::helpers::parse::{self, ParseError};
+use hashbrown::HashSet;
+use tracing::{debug, trace};
+
+crate::example!("16", 46, 51);
+
+/// A non-empty grid.
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    let grid = parse::grid(input, |c| b"./\\|-".contains(&c).then_some(c))?;
+    if grid.is_empty() {
+        return Err(ParseError::new(1, "empty grid"));
+    }
+    Ok(grid)
+}
+
+fn step(dir: i8, pos: (i32, i32)) -> (i32, i32) {
+    match dir {
+        0 => (pos.0 + 1, pos.1),
+        1 => (pos.0, pos.1 - 1),
+        2 => (pos.0 - 1, pos.1),
+        3 => (pos.0, pos.1 + 1),
+        _ => panic!("Invalid direction"),
+    }
+}
+
+/// The tiles each direction's beam has passed: a [`BitGrid`] per direction, or the
+/// `(dir, pos)` pairs for grids too wide for one.
+pub enum Visited {
+    Grids([BitGrid; 4]),
+    Set(HashSet<(i8, (i32, i32))>),
+}
+
+impl Visited {
+    fn new(width: usize, height: usize) -> Self {
+        if width <= MAX_WIDTH {
+            Visited::Grids(std::array::from_fn(|_| BitGrid::new(width, height)))
+        } else {
+            Visited::Set(HashSet::new())
+        }
+    }
+
+    /// Marks `pos` as passed in `dir`, returning whether it was new.
+    fn insert(&mut self, dir: i8, pos: (i32, i32)) -> bool {
+        match self {
+            Visited::Grids(grids) => grids[dir as usize].insert(pos.0 as usize, pos.1 as usize),
+            Visited::Set(set) => set.insert((dir, pos)),
+        }
+    }
+
+    /// The number of tiles passed in any direction.
+    fn tiles(&self) -> u32 {
+        match self {
+            Visited::Grids([east, north, west, south]) => {
+                (&(east | north) | &(west | south)).count_ones()
+            }
+            Visited::Set(set) => set.iter().map(|(_, pos)| pos).collect::<HashSet<_>>().len() as u32,
+        }
+    }
+}
+
+fn move_beam(dir: i8, pos: (i32, i32), grid: &[Vec<u8>], visited: &mut Visited) {
+    let dir = dir.rem_euclid(4);
+    let pos = step(dir, pos);
+    // assert bounds
+    if pos.0 < 0 || pos.1 < 0 || pos.0 >= grid[0].len() as i32 || pos.1 >= grid.len() as i32 {
+        return;
+    }
+    if !visited.insert(dir, pos) {
+        return;
+    }
+    //check tile
+
+    match grid[pos.1 as usize][pos.0 as usize] {
+        b'.' => {
+            //continue straight
+            trace!(?pos, dir, "continue straight");
+            move_beam(dir, pos, grid, visited);
+        }
+        b'/' => {
+            //turn
+            trace!(?pos, dir, "turn");
+            if dir == 0 || dir == 2 {
+                return move_beam(dir + 1, pos, grid, visited);
+            }
+            move_beam(dir - 1, pos, grid, visited);
+        }
+        b'\\' => {
+            //turn
+            trace!(?pos, dir, "turn");
+            if dir == 1 || dir == 3 {
+                return move_beam(dir + 1, pos, grid, visited);
+            }
+            move_beam(dir - 1, pos, grid, visited);
+        }
+        b'-' => {
+            //continue
+            if dir == 0 || dir == 2 {
+                trace!(?pos, dir, "continue straight");
+                return move_beam(dir, pos, grid, visited);
+            }
+            trace!(?pos, dir, "split");
+            move_beam(dir + 1, pos, grid, visited);
+            move_beam(dir - 1, pos, grid, visited);
+        }
+        b'|' => {
+            //continue
+            if dir == 1 || dir == 3 {
+                trace!(?pos, dir, "continue straight");
+                return move_beam(dir, pos, grid, visited);
+            }
+
+            trace!(?pos, dir, "split");
+            move_beam(dir + 1, pos, grid, visited);
+            move_beam(dir - 1, pos, grid, visited);
+        }
+        _ => panic!("Invalid input"),
+    }
+}
+
+fn energized(dir: i8, pos: (i32, i32), grid: &[Vec<u8>]) -> u32 {
+    let mut visited = Visited::new(grid[0].len(), grid.len());
+    move_beam(dir, pos, grid, &mut visited);
+    let tiles = visited.tiles();
+    debug!(?pos, dir, tiles, "energized");
+    tiles
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let input = parse(input).ok()?;
+    Some(energized(0, (-1, 0), &input))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let input = parse(input).ok()?;
+    let (width, height) = (input[0].len() as i32, input.len() as i32);
+    let starts = (0..height)
+        .flat_map(|y| [(0, (-1, y)), (2, (width, y))])
+        .chain((0..width).flat_map(|x| [(1, (x, height)), (3, (x, -1))]))
+        .collect::<Vec<_>>();
+    starts
+        .into_maybe_par_iter()
+        .map(|(dir, pos)| energized(dir, pos, &input))
+        .max()
+}
+
+/// A `size`×`size` contraption with mirrors and splitters on about 10% of the
+/// tiles.
+pub fn generate(size: usize, rng: &mut crate::helpers::generate::Rng) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        out.extend((0..size).map(|_| {
+            if rng.chance(0.1) {
+                *rng.choose(&['/', '\\', '|', '-'])
+            } else {
+                '.'
+            }
+        }));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate::Rng;
+
+    #[test]
+    fn test_wide_grid() {
+        crate::helpers::logging::init_test();
+        let row = format!("{}\\\n", ".".repeat(MAX_WIDTH + 1));
+        assert_eq!(part_one(&row), Some(MAX_WIDTH as u32 + 2));
+        // both representations agree where a grid fits either.
+        let input = generate(40, &mut Rng::new(3));
+        let grid = parse(&input).unwrap();
+        for width in [40, MAX_WIDTH + 1] {
+            let mut visited = Visited::new(width, grid.len());
+            move_beam(0, (-1, 0), &grid, &mut visited);
+            assert_eq!(visited.tiles(), energized(0, (-1, 0), &grid));
+        }
+    }
+}
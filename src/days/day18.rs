@@ -0,0 +1,62 @@
+use crate::helpers::parse::{parse_lines, ParseError};
+use crate::helpers::polygon::Polygon;
+
+crate::example!("18", 62, 952408144115);
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Result<Vec<(i64, i64, i64, i64)>, ParseError> {
+    parse_lines(input, |line| {
+        let (dir, len, hex_string) = crate::scan!(line, "{} {} (#{})" => char, u32, &str)?;
+        let dir = get_dir(dir).ok_or_else(|| ParseError::new(1, "invalid direction"))?;
+        let invalid_color = || ParseError::new(1, format!("invalid color `{}`", hex_string));
+        if hex_string.len() != 6 || !hex_string.is_ascii() {
+            return Err(invalid_color());
+        }
+        let len_long = i64::from_str_radix(&hex_string[0..5], 16).map_err(|_| invalid_color())?;
+        let dir_long = match &hex_string[5..] {
+            "0" => 0,
+            "1" => 3,
+            "2" => 2,
+            "3" => 1,
+            _ => return Err(invalid_color()),
+        };
+        Ok((dir, len as i64, dir_long, len_long))
+    })
+}
+
+fn get_dir(point: char) -> Option<i64> {
+    match point {
+        'R' => Some(0),
+        'U' => Some(1),
+        'L' => Some(2),
+        'D' => Some(3),
+        _ => None,
+    }
+}
+
+/// `None` if the trench is longer than 2^30, which keeps the shoelace sum within
+/// an `i64`.
+fn solve_area(input: Vec<(i64, i64)>) -> Option<u64> {
+    if input.iter().map(|&(_, len)| len).sum::<i64>() > 1 << 30 {
+        return None;
+    }
+    let moves = input.into_iter().map(|(dir, len)| {
+        let step = match dir {
+            0 => [1, 0],
+            1 => [0, -1],
+            2 => [-1, 0],
+            3 => [0, 1],
+            _ => panic!("Invalid direction"),
+        };
+        (step, len)
+    });
+    Some(Polygon::from_moves(moves).enclosed_points() as u64)
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    solve_area(parse(input).ok()?.iter().map(|x| (x.0, x.1)).collect())
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    solve_area(parse(input).ok()?.iter().map(|x| (x.2, x.3)).collect())
+}
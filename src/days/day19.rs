@@ -0,0 +1,242 @@
+use crate::helpers::explain;
+use crate::helpers::interval::{Interval, IntervalBox};
+use crate::helpers::parse::{self, parse_lines, ParseError};
+use hashbrown::{HashMap, HashSet};
+
+crate::example!("19", 19114, 167409079868000);
+
+#[derive(Debug)]
+pub enum Res {
+    Rule(String),
+    End(bool),
+}
+
+#[derive(Debug)]
+pub struct Rule {
+    /// The category compared, 0 to 3 for `x`, `m`, `a` and `s`.
+    pub comp: u8,
+    /// 1 for `>`, -1 for `<` and 0 for the fallback rule, which always matches.
+    pub greater: i32,
+    pub value: u32,
+    pub res: Res,
+}
+
+pub fn parse_res(s: &str) -> Res {
+    match s {
+        "A" => Res::End(true),
+        "R" => Res::End(false),
+        s => Res::Rule(s.to_string()),
+    }
+}
+
+pub fn parse_rule(s: &str) -> Result<Rule, ParseError> {
+    let Some((cond, target)) = s.split_once(':') else {
+        // the fallback rule at the end of every workflow
+        return Ok(Rule {
+            comp: 0,
+            greater: 0,
+            value: 0,
+            res: parse_res(s),
+        });
+    };
+    let comp = match cond.as_bytes().first() {
+        Some(b'x') => 0,
+        Some(b'm') => 1,
+        Some(b'a') => 2,
+        Some(b's') => 3,
+        _ => return Err(ParseError::new(1, "Invalid comparison")),
+    };
+    let greater = match cond.as_bytes().get(1) {
+        Some(b'>') => 1,
+        Some(b'<') => -1,
+        _ => return Err(ParseError::new(2, "Invalid comparison")),
+    };
+    let value = cond[2..]
+        .parse::<u32>()
+        .map_err(|e| ParseError::new(3, e.to_string()))?;
+    Ok(Rule {
+        comp,
+        greater,
+        value,
+        res: parse_res(target),
+    })
+}
+
+pub type Workflows<'a> = HashMap<&'a str, Vec<Rule>>;
+
+/// Checks that every part ends up accepted or rejected: all workflows end in a
+/// fallback rule, lead to known workflows, and never loop back from `in`.
+fn validate(workflows: &Workflows) -> Result<(), ParseError> {
+    fn visit<'a>(
+        id: &'a str,
+        workflows: &'a Workflows,
+        path: &mut Vec<&'a str>,
+        done: &mut HashSet<&'a str>,
+    ) -> Result<(), ParseError> {
+        if done.contains(id) {
+            return Ok(());
+        }
+        if path.contains(&id) {
+            return Err(ParseError::new(1, format!("workflow `{}` loops", id)));
+        }
+        let rules = workflows
+            .get(id)
+            .ok_or_else(|| ParseError::new(1, format!("unknown workflow `{}`", id)))?;
+        if rules.last().is_none_or(|rule| rule.greater != 0) {
+            return Err(ParseError::new(
+                1,
+                format!("workflow `{}` has no fallback", id),
+            ));
+        }
+        path.push(id);
+        for rule in rules {
+            if let Res::Rule(next) = &rule.res {
+                visit(next, workflows, path, done)?;
+            }
+        }
+        path.pop();
+        done.insert(id);
+        Ok(())
+    }
+    visit("in", workflows, &mut Vec::new(), &mut HashSet::new())
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Result<(Workflows<'_>, Vec<Vec<u32>>), ParseError> {
+    let mut blocks = parse::blocks(input);
+    let (_, workflows) = blocks.next().unwrap_or_default();
+    let workflows = parse_lines(workflows, |line| {
+        let (id, rules) = crate::scan!(line, "{}{{{}}}" => &str, &str)?;
+        // the 1-based column of each rule, after `id{`.
+        let mut column = id.len() + 2;
+        let rules = rules
+            .split(',')
+            .map(|rule| {
+                let parsed = parse_rule(rule).map_err(|e| e.right(column - 1));
+                column += rule.len() + 1;
+                parsed
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok((id, rules))
+    })?;
+    let (offset, parts) = blocks.next().unwrap_or_default();
+    let parts = parse_lines(parts, |line| {
+        let (x, m, a, s) =
+            crate::scan!(line, "{{x={},m={},a={},s={}}}" => u32, u32, u32, u32)?;
+        Ok(vec![x, m, a, s])
+    })
+    .map_err(|e| e.below(offset))?;
+    let workflows = workflows.into_iter().collect();
+    validate(&workflows)?;
+    Ok((workflows, parts))
+}
+
+/// Whether `part` is accepted, calling `visit` with each workflow it goes through.
+pub fn route<'a>(part: &[u32], rule_map: &'a Workflows, mut visit: impl FnMut(&'a str)) -> bool {
+    let mut loc = "in";
+    'outer: loop {
+        visit(loc);
+        for rule in &rule_map[loc] {
+            let greater = rule.greater as i64;
+            if greater * part[rule.comp as usize] as i64 > greater * rule.value as i64
+                || rule.greater == 0
+            {
+                match &rule.res {
+                    Res::Rule(s) => {
+                        loc = s;
+                        continue 'outer;
+                    }
+                    Res::End(accepted) => return *accepted,
+                }
+            }
+        }
+    }
+}
+
+/// The part's rating if it's accepted, else 0. `None` if the rating overflows.
+pub fn rating(part: &[u32], rule_map: &Workflows) -> Option<u32> {
+    if route(part, rule_map, |_| ()) {
+        part.iter().try_fold(0u32, |sum, &x| sum.checked_add(x))
+    } else {
+        Some(0)
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (rule_map, parts) = parse(input).ok()?;
+    explain::table("parts", &["x", "m", "a", "s", "path", "rating"], || {
+        parts.iter().map(|part| {
+            let mut path = Vec::new();
+            let accepted = route(part, &rule_map, |loc| path.push(loc));
+            let mut row = part.iter().map(u32::to_string).collect::<Vec<_>>();
+            path.push(if accepted { "A" } else { "R" });
+            row.push(path.join(" -> "));
+            row.push(rating(part, &rule_map).map_or("overflow".to_string(), |r| r.to_string()));
+            row
+        })
+    });
+    parts
+        .iter()
+        .try_fold(0u32, |sum, part| sum.checked_add(rating(part, &rule_map)?))
+}
+
+pub type Parts = IntervalBox<i64, 4>;
+
+/// Splits `parts` into the ones matching the rule and the ones falling through.
+fn split_parts(parts: Parts, rule: &Rule) -> (Parts, Parts) {
+    let axis = rule.comp as usize;
+    if rule.greater == 1 {
+        let (remaining, passing) = parts.split_at(axis, rule.value as i64 + 1);
+        (passing, remaining)
+    } else {
+        parts.split_at(axis, rule.value as i64)
+    }
+}
+
+fn return_combinations(rule: &Rule, parts: Parts, rule_map: &Workflows) -> i64 {
+    match &rule.res {
+        Res::Rule(s) => get_combinations(s.as_str(), parts, rule_map),
+        Res::End(true) => parts.volume(),
+        Res::End(false) => 0,
+    }
+}
+
+fn get_combinations(loc: &str, parts: Parts, rule_map: &Workflows) -> i64 {
+    let mut total = 0;
+    let mut parts = parts;
+    if parts.is_empty() {
+        return 0;
+    }
+    for rule in &rule_map[loc] {
+        match rule.greater {
+            0 => return total + return_combinations(rule, parts, rule_map),
+            1 | -1 => {
+                let (passing, remaining) = split_parts(parts, rule);
+                parts = remaining;
+                total += return_combinations(rule, passing, rule_map);
+            }
+            _ => panic!("Invalid greater"),
+        }
+    }
+    total
+}
+
+pub fn part_two(input: &str) -> Option<i64> {
+    let (rule_map, _) = parse(input).ok()?;
+    let parts = IntervalBox::new([Interval::new(1, 4001); 4]);
+    Some(get_combinations("in", parts, &rule_map))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_columns() {
+        crate::helpers::logging::init_test();
+        let err = parse("in{x>10:A,q<5:R,A}\n\n{x=1,m=1,a=1,s=1}").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(1), 11));
+        let err = parse("in{x>1y:A,R}\n\n{x=1,m=1,a=1,s=1}").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(1), 6));
+    }
+}
@@ -0,0 +1,203 @@
+use crate::helpers::gcd;
+use crate::helpers::parse::{parse_lines, ParseError};
+use hashbrown::{HashMap, HashSet};
+use itertools::Itertools;
+use std::collections::VecDeque;
+
+crate::example!("20", 11687500, _);
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ModuleType<'a> {
+    Broadcaster,
+    FlipFlop(bool),
+    Conjunction(HashMap<&'a str, bool>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Module<'a> {
+    pub module_type: ModuleType<'a>,
+    pub outputs: Vec<&'a str>,
+}
+
+/// Pulses a single button press may send before it's taken to never settle.
+const MAX_PULSES: usize = 1 << 16;
+
+/// Button presses to wait for every cluster to cycle.
+const MAX_PRESSES: u64 = 1 << 13;
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse(input: &str) -> Result<HashMap<&str, Module<'_>>, ParseError> {
+    let mut modules: HashMap<&str, Module> = parse_lines(input, |line| {
+        let (left, right) = crate::scan!(line, "{} -> {}" => &str, &str)?;
+        let outputs: Vec<&str> = right.split(", ").collect();
+        let (name, module_type) = if let Some(name) = left.strip_prefix('%') {
+            (name, ModuleType::FlipFlop(false))
+        } else if let Some(name) = left.strip_prefix('&') {
+            (name, ModuleType::Conjunction(HashMap::new()))
+        } else {
+            (left, ModuleType::Broadcaster)
+        };
+        Ok((
+            name,
+            Module {
+                module_type,
+                outputs,
+            },
+        ))
+    })?
+    .into_iter()
+    .collect();
+    //prime maps
+    for (key, module) in modules.clone() {
+        for output in module.outputs.iter() {
+            if let Some(Module {
+                module_type: ModuleType::Conjunction(map),
+                ..
+            }) = modules.get_mut(output)
+            {
+                map.insert(key, false);
+            }
+        }
+    }
+    Ok(modules)
+}
+
+/// Delivers a pulse from `from` to `key`, returning the pulses it sends on as
+/// `(target, from, hi)`.
+pub fn signal<'a>(
+    modules: &mut HashMap<&str, Module<'a>>,
+    from: &'a str,
+    key: &'a str,
+    hi: bool,
+) -> Vec<(&'a str, &'a str, bool)> {
+    let module = modules.get_mut(key);
+    if module.is_none() {
+        return Vec::new();
+    }
+    let module = module.unwrap();
+    match (&mut module.module_type, hi) {
+        (ModuleType::Broadcaster, hi) => module
+            .outputs
+            .iter()
+            .map(|&output| (output, key, hi))
+            .collect(),
+        (ModuleType::FlipFlop(state), false) => {
+            *state = !*state;
+            module
+                .outputs
+                .iter()
+                .map(|&output| (output, key, *state))
+                .collect()
+        }
+        (ModuleType::FlipFlop(_), true) => Vec::new(),
+        (ModuleType::Conjunction(map), hi) => {
+            if let Some(last) = map.get_mut(&from) {
+                *last = hi;
+            }
+            module
+                .outputs
+                .iter()
+                .map(|&output| (output, key, map.is_empty() || map.values().any(|&v| !v)))
+                .collect()
+        }
+    }
+}
+
+/// Presses the button once, calling `on_pulse` with every pulse's target and level.
+/// `None` if the pulses don't settle.
+pub fn press<'a>(
+    modules: &mut HashMap<&str, Module<'a>>,
+    mut on_pulse: impl FnMut(&'a str, bool),
+) -> Option<()> {
+    let mut queue = VecDeque::from([("broadcaster", "button", false)]);
+    let mut pulses = 0;
+    while let Some((key, from, hi)) = queue.pop_front() {
+        pulses += 1;
+        if pulses > MAX_PULSES {
+            return None;
+        }
+        on_pulse(key, hi);
+        let outputs = signal(modules, from, key, hi);
+        queue.extend(outputs);
+    }
+    Some(())
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let mut modules = parse(input).ok()?;
+
+    let mut num_signals = [0; 2];
+    for _ in 0..1000 {
+        press(&mut modules, |_, hi| num_signals[hi as usize] += 1u64)?;
+    }
+    Some(num_signals.iter().product::<u64>())
+}
+
+fn fill_cluster<'a>(
+    modules: &HashMap<&str, Module<'a>>,
+    cluster: &mut HashSet<&'a str>,
+    key: &'a str,
+    last_conjunction: &'a str,
+) -> Option<()> {
+    if key == last_conjunction || cluster.contains(key) {
+        return Some(());
+    }
+    cluster.insert(key);
+    for output in modules.get(key)?.outputs.iter() {
+        fill_cluster(modules, cluster, output, last_conjunction)?;
+    }
+    Some(())
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let mut modules = parse(input).ok()?;
+
+    //find last conjunction
+    let last_conjunction = *modules
+        .iter()
+        .find(|(_, module)| module.outputs == vec!["rx"])?
+        .0;
+
+    //find the clusters
+    let mut clusters = Vec::new();
+    for node in modules.get("broadcaster")?.outputs.iter() {
+        let mut cluster = HashSet::new();
+        fill_cluster(&modules, &mut cluster, node, last_conjunction)?;
+        let last = *cluster
+            .iter()
+            .find(|&&n| modules[n].outputs == vec![last_conjunction])?;
+        clusters.push((cluster.into_iter().collect::<Vec<&str>>(), last));
+    }
+    tracing::debug!(last_conjunction, clusters = clusters.len(), "found the clusters");
+
+    let mut cycles = Vec::new();
+    for iter in 1..=MAX_PRESSES {
+        press(&mut modules, |key, hi| {
+            if clusters.iter().map(|(_, b)| *b).contains(&key) && !hi {
+                tracing::debug!(module = key, presses = iter, "cluster sent a low pulse");
+                cycles.push(iter);
+            }
+        })?;
+        if cycles.len() >= clusters.len() {
+            break;
+        }
+    }
+    if cycles.len() < clusters.len() {
+        return None;
+    }
+
+    cycles[..clusters.len()]
+        .iter()
+        .try_fold(1u64, |acc, &n| (acc / gcd(acc, n)).checked_mul(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_two() {
+        crate::helpers::logging::init_test();
+        assert_eq!(part_two(EXAMPLE), None);
+    }
+}
@@ -0,0 +1,118 @@
+/*
+ * Encrypted puzzle inputs, so they can be committed without publishing them.
+ *
+ * `src/inputs/NN.txt.enc` is the input encrypted with ChaCha20-Poly1305: a random
+ * 12-byte nonce followed by the ciphertext. The 32-byte key is read as hex from
+ * `AOC_KEY` or the (ignored) `.aoc-key` file. See `src/bin/inputs.rs`.
+ */
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+pub use chacha20poly1305::Key;
+
+pub const KEY_ENV_VAR: &str = "AOC_KEY";
+pub const KEY_FILE: &str = ".aoc-key";
+pub const EXTENSION: &str = "enc";
+
+const NONCE_LEN: usize = 12;
+
+/// The key from `AOC_KEY`, or else from `.aoc-key`.
+pub fn key() -> Result<Key, String> {
+    let hex = match env::var(KEY_ENV_VAR) {
+        Ok(hex) => hex,
+        Err(_) => fs::read_to_string(KEY_FILE)
+            .map_err(|_| format!("no key in {} or {}", KEY_ENV_VAR, KEY_FILE))?,
+    };
+    parse_key(hex.trim())
+}
+
+pub fn parse_key(hex: &str) -> Result<Key, String> {
+    if hex.len() != 64 || !hex.is_ascii() {
+        return Err("the key must be 64 hex digits".to_string());
+    }
+    let bytes = (0..32)
+        .map(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| "the key must be 64 hex digits".to_string())?;
+    Ok(*Key::from_slice(&bytes))
+}
+
+/// A new random key, as hex.
+pub fn generate_key() -> String {
+    ChaCha20Poly1305::generate_key(&mut OsRng)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+pub fn encrypt(key: &Key, plaintext: &str) -> Vec<u8> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = ChaCha20Poly1305::new(key)
+        .encrypt(&nonce, plaintext.as_bytes())
+        .expect("encryption of an in-memory buffer can't fail");
+    [nonce.as_slice(), &ciphertext].concat()
+}
+
+pub fn decrypt(key: &Key, data: &[u8]) -> Result<String, String> {
+    if data.len() < NONCE_LEN {
+        return Err("too short to be encrypted".to_string());
+    }
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    let plaintext = ChaCha20Poly1305::new(key)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "wrong key or corrupted file".to_string())?;
+    String::from_utf8(plaintext).map_err(|e| e.to_string())
+}
+
+/// `NN.txt.enc` next to `NN.txt`.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(".");
+    name.push(EXTENSION);
+    path.with_file_name(name)
+}
+
+/// Decrypts the encrypted counterpart of `path`.
+pub fn read_encrypted(path: &Path) -> Result<String, String> {
+    let path = encrypted_path(path);
+    let data = fs::read(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    decrypt(&key()?, &data).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let key = parse_key(&generate_key()).unwrap();
+        let input = "467..114..\n...*......\n";
+        let encrypted = encrypt(&key, input);
+        assert_ne!(&encrypted[NONCE_LEN..], input.as_bytes());
+        assert_eq!(decrypt(&key, &encrypted), Ok(input.to_string()));
+        // nonces are random, so equal inputs don't encrypt the same.
+        assert_ne!(encrypt(&key, input), encrypted);
+
+        let other = parse_key(&generate_key()).unwrap();
+        assert!(decrypt(&other, &encrypted).is_err());
+        assert!(decrypt(&key, &encrypted[..5]).is_err());
+    }
+
+    #[test]
+    fn test_parse_key() {
+        assert!(parse_key(&"ab".repeat(32)).is_ok());
+        assert!(parse_key("abc").is_err());
+        assert!(parse_key(&"zz".repeat(32)).is_err());
+        assert!(parse_key(&"é".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn test_encrypted_path() {
+        assert_eq!(
+            encrypted_path(Path::new("src/inputs/07.txt")),
+            Path::new("src/inputs/07.txt.enc")
+        );
+    }
+}
@@ -2,6 +2,23 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+pub mod bitgrid;
+pub mod bytes;
+pub mod cycle;
+pub mod differential;
+pub mod explain;
+pub mod fuzz;
+pub mod generate;
+pub mod interval;
+pub mod logging;
+pub mod math;
+pub mod memo;
+pub mod parallel;
+pub mod parse;
+pub mod polygon;
+pub mod visualize;
+
+pub use math::{gcd, lcm};
 
 #[macro_export]
 macro_rules! skip_fail {
@@ -9,7 +26,7 @@ macro_rules! skip_fail {
         match $res {
             Ok(val) => val,
             Err(e) => {
-                warn!("An error: {}; skipped.", e);
+                $crate::tracing::warn!("An error: {}; skipped.", e);
                 continue;
             }
         }
@@ -17,7 +34,7 @@ macro_rules! skip_fail {
 }
 
 //Safe 2d getter
-pub fn get_safe<T: Copy>(coord: [isize; 2], graph: &Vec<Vec<T>>) -> Option<T> {
+pub fn get_safe<T: Copy>(coord: [isize; 2], graph: &[Vec<T>]) -> Option<T> {
     if coord[0] < 0 || coord[1] < 0 {
         return None;
     }
@@ -25,24 +42,3 @@ pub fn get_safe<T: Copy>(coord: [isize; 2], graph: &Vec<Vec<T>>) -> Option<T> {
     let x: usize = coord[0].try_into().ok()?;
     Some(*graph.get(y)?.get(x)?)
 }
-
-pub fn gcd(n1: u32, n2: u32) -> u32 {
-    let mut x;
-    let mut y;
-    if n1 > n2 {
-        x = n1;
-        y = n2;
-    } else {
-        x = n2;
-        y = n1;
-    }
-
-    let mut rem = x % y;
-
-    while rem != 0 {
-        x = y;
-        y = rem;
-        rem = x % y;
-    }
-    y
-}
@@ -0,0 +1,306 @@
+//! A grid of booleans packed into one `u128` per row.
+//!
+//! Bit `x` of row `y` is the cell at column `x`, so whole rows can be compared,
+//! counted and shifted with single word operations. Grids are at most 128 wide.
+use std::fmt;
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+pub const MAX_WIDTH: usize = 128;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    rows: Vec<u128>,
+}
+
+impl BitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        assert!(width <= MAX_WIDTH, "BitGrid is at most {} wide", MAX_WIDTH);
+        Self {
+            width,
+            rows: vec![0; height],
+        }
+    }
+
+    /// A grid with the size of `input` where cells matching `f` are set. Panics
+    /// unless `input` is a rectangle at most 128 wide.
+    pub fn parse(input: &str, f: impl Fn(u8) -> bool) -> Self {
+        Self::try_parse(input, f).expect("input is not a rectangle at most 128 wide")
+    }
+
+    /// Like [`BitGrid::parse`], but `None` for lines of different lengths or
+    /// longer than 128.
+    pub fn try_parse(input: &str, f: impl Fn(u8) -> bool) -> Option<Self> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let width = lines.first().map_or(0, |line| line.len());
+        if width > MAX_WIDTH || lines.iter().any(|line| line.len() != width) {
+            return None;
+        }
+        let mut grid = Self::new(width, lines.len());
+        for (row, line) in grid.rows.iter_mut().zip(lines) {
+            for (x, c) in line.bytes().enumerate() {
+                *row |= (f(c) as u128) << x;
+            }
+        }
+        Some(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// A row with every cell within the width set.
+    pub fn row_mask(&self) -> u128 {
+        u128::MAX
+            .checked_shr((MAX_WIDTH - self.width) as u32)
+            .unwrap_or(0)
+    }
+
+    pub fn in_bounds(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.rows[y] >> x & 1 == 1
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        debug_assert!(x < self.width);
+        if value {
+            self.rows[y] |= 1 << x;
+        } else {
+            self.rows[y] &= !(1 << x);
+        }
+    }
+
+    /// Sets the cell and returns whether it was newly set.
+    pub fn insert(&mut self, x: usize, y: usize) -> bool {
+        let was_set = self.get(x, y);
+        self.set(x, y, true);
+        !was_set
+    }
+
+    pub fn rows(&self) -> &[u128] {
+        &self.rows
+    }
+
+    pub fn row(&self, y: usize) -> u128 {
+        self.rows[y]
+    }
+
+    pub fn set_row(&mut self, y: usize, row: u128) {
+        self.rows[y] = row & self.row_mask();
+    }
+
+    /// Column `x` as a word where bit `y` is the cell at row `y`. Requires a height
+    /// of at most 128.
+    pub fn column(&self, x: usize) -> u128 {
+        self.rows
+            .iter()
+            .enumerate()
+            .fold(0, |column, (y, row)| column | (row >> x & 1) << y)
+    }
+
+    /// Mirrors the grid along the diagonal, so columns become rows.
+    pub fn transpose(&self) -> Self {
+        let mut transposed = Self::new(self.height(), self.width);
+        for x in 0..self.width {
+            transposed.rows[x] = self.column(x);
+        }
+        transposed
+    }
+
+    pub fn count_ones(&self) -> u32 {
+        self.rows.iter().map(|row| row.count_ones()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.iter().all(|&row| row == 0)
+    }
+
+    /// The number of cells that differ between both grids.
+    pub fn diff(&self, other: &Self) -> u32 {
+        (self ^ other).count_ones()
+    }
+
+    /// The set cells as `(x, y)` pairs, row by row.
+    pub fn iter_ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.rows.iter().enumerate().flat_map(|(y, &row)| {
+            let mut row = row;
+            std::iter::from_fn(move || {
+                if row == 0 {
+                    return None;
+                }
+                let x = row.trailing_zeros() as usize;
+                row &= row - 1;
+                Some((x, y))
+            })
+        })
+    }
+
+    /// Moves every cell one column right; cells shifted past the edge are lost.
+    pub fn shift_east(&self) -> Self {
+        let mask = self.row_mask();
+        self.map_rows(|row| row << 1 & mask)
+    }
+
+    pub fn shift_west(&self) -> Self {
+        self.map_rows(|row| row >> 1)
+    }
+
+    /// Moves every cell one row up; the bottom row becomes empty.
+    pub fn shift_north(&self) -> Self {
+        let mut rows = self.rows[1.min(self.height())..].to_vec();
+        rows.resize(self.height(), 0);
+        Self {
+            width: self.width,
+            rows,
+        }
+    }
+
+    pub fn shift_south(&self) -> Self {
+        let mut rows = vec![0; 1.min(self.height())];
+        rows.extend_from_slice(&self.rows[..self.height().saturating_sub(1)]);
+        Self {
+            width: self.width,
+            rows,
+        }
+    }
+
+    fn map_rows(&self, f: impl Fn(u128) -> u128) -> Self {
+        Self {
+            width: self.width,
+            rows: self.rows.iter().map(|&row| f(row)).collect(),
+        }
+    }
+
+    fn zip_rows(&self, other: &Self, f: impl Fn(u128, u128) -> u128) -> Self {
+        assert_eq!(
+            (self.width, self.height()),
+            (other.width, other.height()),
+            "grid sizes differ"
+        );
+        Self {
+            width: self.width,
+            rows: self
+                .rows
+                .iter()
+                .zip(other.rows.iter())
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+        }
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, other: Self) -> BitGrid {
+        self.zip_rows(other, |a, b| a & b)
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, other: Self) -> BitGrid {
+        self.zip_rows(other, |a, b| a | b)
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, other: Self) -> BitGrid {
+        self.zip_rows(other, |a, b| a ^ b)
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        let mask = self.row_mask();
+        self.map_rows(|row| !row & mask)
+    }
+}
+
+impl fmt::Display for BitGrid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height() {
+            let line = (0..self.width)
+                .map(|x| if self.get(x, y) { '#' } else { '.' })
+                .collect::<String>();
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHAPE: &str = "#..\n.##\n..#\n#..";
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = BitGrid::parse(SHAPE, |c| c == b'#');
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid.rows(), &[0b001, 0b110, 0b100, 0b001]);
+        assert_eq!(grid.column(0), 0b1001);
+        assert_eq!(grid.count_ones(), 5);
+        assert_eq!(grid.to_string(), format!("{}\n", SHAPE));
+        assert_eq!(
+            grid.iter_ones().collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1), (2, 1), (2, 2), (0, 3)]
+        );
+    }
+
+    #[test]
+    fn test_try_parse() {
+        assert!(BitGrid::try_parse("#.\n#", |c| c == b'#').is_none());
+        assert!(BitGrid::try_parse(&".".repeat(MAX_WIDTH + 1), |c| c == b'#').is_none());
+        assert_eq!(
+            BitGrid::try_parse("", |c| c == b'#'),
+            Some(BitGrid::new(0, 0))
+        );
+    }
+
+    #[test]
+    fn test_shifts() {
+        let grid = BitGrid::parse(SHAPE, |c| c == b'#');
+        assert_eq!(grid.shift_east().to_string(), ".#.\n..#\n...\n.#.\n");
+        assert_eq!(grid.shift_west().to_string(), "...\n##.\n.#.\n...\n");
+        assert_eq!(grid.shift_north().to_string(), ".##\n..#\n#..\n...\n");
+        assert_eq!(grid.shift_south().to_string(), "...\n#..\n.##\n..#\n");
+        assert_eq!((!&grid).count_ones(), 7);
+    }
+
+    #[test]
+    fn test_transpose_and_diff() {
+        let grid = BitGrid::parse(SHAPE, |c| c == b'#');
+        let transposed = grid.transpose();
+        assert_eq!(transposed.to_string(), "#..#\n.#..\n.##.\n");
+        assert_eq!(transposed.transpose(), grid);
+        assert_eq!(grid.diff(&grid.shift_east()), 6);
+
+        let mut other = grid.clone();
+        assert!(!other.insert(1, 1));
+        assert!(other.insert(1, 0));
+        assert_eq!(grid.diff(&other), 1);
+    }
+
+    #[test]
+    fn test_full_width() {
+        let mut grid = BitGrid::new(MAX_WIDTH, 1);
+        grid.set(MAX_WIDTH - 1, 0, true);
+        assert_eq!(grid.row_mask(), u128::MAX);
+        assert!(grid.shift_east().is_empty());
+        assert_eq!(grid.shift_west().row(0), 1 << (MAX_WIDTH - 2));
+    }
+}
@@ -0,0 +1,167 @@
+//! Byte-slice input for hot paths, where `&str` parsing would dominate the runtime.
+//!
+//! Solvers still take `&str`; `input.as_bytes()` is free, and [`lines`] handles
+//! `\r\n` itself, so inputs can be used as read with [`read_bytes`](crate::read_bytes).
+//!
+//! Example: `let rows = bytes::lines(input.as_bytes()).map(bytes::ints::<u32>);`
+
+/// Iterator over the lines of a byte slice, see [`lines`].
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (line, rest) = match self.rest.iter().position(|&c| c == b'\n') {
+            Some(end) => (&self.rest[..end], &self.rest[end + 1..]),
+            None => (self.rest, &[][..]),
+        };
+        self.rest = rest;
+        Some(line.strip_suffix(b"\r").unwrap_or(line))
+    }
+}
+
+/// The lines of `input` without their `\n` or `\r\n`, like [`str::lines`].
+pub fn lines(input: &[u8]) -> Lines<'_> {
+    Lines { rest: input }
+}
+
+/// An integer that can be read from ASCII digits, see [`int`].
+pub trait Int: Copy {
+    const SIGNED: bool;
+
+    /// `self * 10 + digit`, or `-` for negative numbers; `None` on overflow.
+    fn push_digit(self, digit: u8, negative: bool) -> Option<Self>;
+    fn zero() -> Self;
+}
+
+macro_rules! impl_int {
+    ($signed:literal => $($t:ty),*) => {$(
+        impl Int for $t {
+            const SIGNED: bool = $signed;
+
+            #[inline]
+            fn push_digit(self, digit: u8, negative: bool) -> Option<Self> {
+                let shifted = self.checked_mul(10)?;
+                if negative {
+                    shifted.checked_sub(digit as $t)
+                } else {
+                    shifted.checked_add(digit as $t)
+                }
+            }
+
+            #[inline]
+            fn zero() -> Self {
+                0
+            }
+        }
+    )*};
+}
+
+impl_int!(false => u8, u16, u32, u64, u128, usize);
+impl_int!(true => i8, i16, i32, i64, i128, isize);
+
+/// Parses digits with an optional leading `-` (for signed `T`). `None` if
+/// `bytes` is empty, has anything else in it, or overflows `T`.
+#[inline]
+pub fn int<T: Int>(bytes: &[u8]) -> Option<T> {
+    let (negative, digits) = match bytes {
+        [b'-', digits @ ..] if T::SIGNED => (true, digits),
+        digits => (false, digits),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    digits.iter().try_fold(T::zero(), |n, &c| {
+        c.is_ascii_digit()
+            .then(|| n.push_digit(c - b'0', negative))
+            .flatten()
+    })
+}
+
+/// Iterator over the integers in a byte slice, see [`ints`].
+#[derive(Debug, Clone)]
+pub struct Ints<'a, T> {
+    rest: &'a [u8],
+    _int: std::marker::PhantomData<T>,
+}
+
+impl<T: Int> Iterator for Ints<'_, T> {
+    /// `None` for an integer that overflows `T`.
+    type Item = Option<T>;
+
+    fn next(&mut self) -> Option<Option<T>> {
+        let start = self.rest.iter().position(u8::is_ascii_digit)?;
+        // `rest` starts after a digit, so a sign is never cut off.
+        let negative = T::SIGNED && start > 0 && self.rest[start - 1] == b'-';
+        let digits = &self.rest[start..];
+        let len = digits
+            .iter()
+            .position(|c| !c.is_ascii_digit())
+            .unwrap_or(digits.len());
+        self.rest = &digits[len..];
+        Some(
+            digits[..len]
+                .iter()
+                .try_fold(T::zero(), |n, &c| n.push_digit(c - b'0', negative)),
+        )
+    }
+}
+
+/// Every integer in `bytes`, e.g. `[-3, 14]` for `b"x=-3, y=14"`, like
+/// [`parse::ints`](super::parse::ints) but without allocating. A `-` only counts
+/// as a sign if `T` is signed.
+pub fn ints<T: Int>(bytes: &[u8]) -> Ints<'_, T> {
+    Ints {
+        rest: bytes,
+        _int: std::marker::PhantomData,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let input = b"a\r\nbc\n\nd\r\n";
+        assert_eq!(
+            lines(input).collect::<Vec<_>>(),
+            vec![&b"a"[..], b"bc", b"", b"d"]
+        );
+        assert_eq!(lines(b"a\nb").count(), 2);
+        assert_eq!(lines(b"").count(), 0);
+    }
+
+    #[test]
+    fn test_int() {
+        assert_eq!(int::<u32>(b"4294967295"), Some(u32::MAX));
+        assert_eq!(int::<u32>(b"4294967296"), None);
+        assert_eq!(int::<i8>(b"-128"), Some(i8::MIN));
+        assert_eq!(int::<u8>(b"-1"), None);
+        assert_eq!(int::<i32>(b"-"), None);
+        assert_eq!(int::<i32>(b"1a"), None);
+        assert_eq!(int::<i32>(b""), None);
+    }
+
+    #[test]
+    fn test_ints() {
+        let ints_of = |bytes| ints::<i32>(bytes).collect::<Option<Vec<_>>>();
+        assert_eq!(ints_of(b"x=-3, y=14 z-5"), Some(vec![-3, 14, -5]));
+        assert_eq!(
+            ints::<u32>(b"x=-3, y=14").collect::<Option<Vec<_>>>(),
+            Some(vec![3, 14])
+        );
+        assert_eq!(ints_of(b"no numbers"), Some(vec![]));
+        assert_eq!(
+            ints::<u8>(b"1 300 2").collect::<Vec<_>>(),
+            vec![Some(1), None, Some(2)]
+        );
+    }
+}
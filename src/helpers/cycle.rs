@@ -0,0 +1,168 @@
+//! Cycle detection for iterated functions `x_{i+1} = step(x_i)`.
+//!
+//! All detectors return a [`Cycle`] where `mu` is the index of the first state
+//! on the cycle and `lambda` is the cycle length, so `x_i == x_{i + lambda}` for
+//! every `i >= mu`.
+use hashbrown::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub mu: usize,
+    pub lambda: usize,
+}
+
+impl Cycle {
+    /// The smallest index whose state equals the state after `n` steps.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.mu {
+            n
+        } else {
+            self.mu + (n - self.mu) % self.lambda
+        }
+    }
+}
+
+/// Detects the cycle by remembering every state. Exact, and visits each state
+/// once, at the cost of keeping them all in memory.
+pub fn find_cycle<S, F>(initial: S, step: F) -> Cycle
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    history(initial, step, usize::MAX).1.unwrap()
+}
+
+/// The state after `n` steps, skipping ahead as soon as a cycle is found.
+pub fn nth_state<S, F>(initial: S, step: F, n: usize) -> S
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let (mut states, cycle) = history(initial, step, n);
+    let i = cycle.map_or(n, |cycle| cycle.reduce(n));
+    states.swap_remove(i)
+}
+
+/// Iterates until `limit` steps were taken or a state repeats. Returns every state
+/// seen in order, plus the cycle if one was found.
+fn history<S, F>(initial: S, mut step: F, limit: usize) -> (Vec<S>, Option<Cycle>)
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut states = vec![initial];
+    for i in 0..limit {
+        let state = &states[i];
+        if let Some(&mu) = seen.get(state) {
+            tracing::debug!(mu, lambda = i - mu, "found a cycle");
+            return (states, Some(Cycle { mu, lambda: i - mu }));
+        }
+        seen.insert(state.clone(), i);
+        let next = step(state);
+        states.push(next);
+    }
+    (states, None)
+}
+
+/// Brent's algorithm: constant memory, typically fewer `step` calls than Floyd.
+pub fn brent<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    // find lambda by teleporting the tortoise to the hare at every power of two.
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        hare = step(&hare);
+        lambda += 1;
+    }
+
+    // find mu with two pointers lambda steps apart.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..lambda {
+        hare = step(&hare);
+    }
+    let mut mu = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        mu += 1;
+    }
+    Cycle { mu, lambda }
+}
+
+/// Floyd's tortoise and hare: constant memory.
+pub fn floyd<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> S,
+{
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let half = step(&hare);
+        hare = step(&half);
+    }
+
+    let mut mu = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        mu += 1;
+    }
+
+    let mut lambda = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        lambda += 1;
+    }
+    Cycle { mu, lambda }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 2 -> ...
+    fn step(x: &u32) -> u32 {
+        if *x == 6 {
+            2
+        } else {
+            x + 1
+        }
+    }
+
+    #[test]
+    fn test_detectors_agree() {
+        let expected = Cycle { mu: 2, lambda: 5 };
+        assert_eq!(find_cycle(0, step), expected);
+        assert_eq!(brent(0, step), expected);
+        assert_eq!(floyd(0, step), expected);
+        assert_eq!(find_cycle(4, step), Cycle { mu: 0, lambda: 5 });
+        assert_eq!(brent(7, |_| 7), Cycle { mu: 0, lambda: 1 });
+        assert_eq!(floyd(7, |_| 7), Cycle { mu: 0, lambda: 1 });
+    }
+
+    #[test]
+    fn test_nth_state() {
+        let naive = |n| (0..n).fold(0, |x, _| step(&x));
+        for n in [0, 1, 2, 6, 7, 12, 1_000] {
+            assert_eq!(nth_state(0, step, n), naive(n));
+        }
+        assert_eq!(nth_state(0, step, 1_000_000_000_000), 5);
+    }
+}
@@ -0,0 +1,219 @@
+//! Differential testing of an optimized solution against a reference one.
+//!
+//! Both run on inputs from a day's seeded generator (see
+//! [`generate`](super::generate)). On a mismatch, the input is shrunk, first by
+//! regenerating it at smaller sizes and then by deleting lines, to the smallest
+//! one that still disagrees.
+//!
+//! ```ignore
+//! #[test]
+//! fn test_part_one_against_reference() {
+//!     Differential::new(generate, reference::part_one, part_one).assert();
+//! }
+//! ```
+//!
+//! Inputs the reference panics on are skipped, so shrinking may produce invalid
+//! inputs without reporting them. A panic in the optimized solution alone is a
+//! mismatch. Set `AOC_CASES` to run more cases than the default.
+use super::generate::{Generator, Rng};
+use crate::runner::panic_message;
+use std::fmt::{self, Debug};
+use std::panic::{self, UnwindSafe};
+
+pub const CASES_ENV_VAR: &str = "AOC_CASES";
+
+pub struct Differential<O> {
+    generate: Generator,
+    reference: fn(&str) -> O,
+    optimized: fn(&str) -> O,
+    cases: usize,
+    max_size: usize,
+    seed: u64,
+}
+
+/// A solution's result, or the message it panicked with.
+pub type Outcome<O> = Result<O, String>;
+
+#[derive(Debug)]
+pub struct Counterexample<O> {
+    pub seed: u64,
+    pub size: usize,
+    pub input: String,
+    pub reference: Outcome<O>,
+    pub optimized: Outcome<O>,
+}
+
+impl<O: Debug> fmt::Display for Counterexample<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "solutions disagree (seed {}, size {}, shrunk):",
+            self.seed, self.size
+        )?;
+        writeln!(f, "{}", self.input.trim_end())?;
+        writeln!(f, "reference: {:?}", self.reference)?;
+        write!(f, "optimized: {:?}", self.optimized)
+    }
+}
+
+fn outcome<O>(solver: fn(&str) -> O, input: &str) -> Outcome<O> {
+    let input = input.to_string();
+    run_catching(move || solver(&input))
+}
+
+fn run_catching<O>(f: impl FnOnce() -> O + UnwindSafe) -> Outcome<O> {
+    panic::catch_unwind(f).map_err(|payload| panic_message(&*payload))
+}
+
+impl<O: PartialEq + Debug> Differential<O> {
+    pub fn new(generate: Generator, reference: fn(&str) -> O, optimized: fn(&str) -> O) -> Self {
+        let cases = std::env::var(CASES_ENV_VAR)
+            .ok()
+            .and_then(|cases| cases.parse().ok())
+            .unwrap_or(50);
+        Self {
+            generate,
+            reference,
+            optimized,
+            cases,
+            max_size: 20,
+            seed: 0,
+        }
+    }
+
+    pub fn cases(self, cases: usize) -> Self {
+        Self { cases, ..self }
+    }
+
+    /// Inputs are generated with sizes up to `max_size`; the reference is usually
+    /// too slow for much more.
+    pub fn max_size(self, max_size: usize) -> Self {
+        Self { max_size, ..self }
+    }
+
+    /// The seed of the first case; case `i` uses `seed + i`.
+    pub fn seed(self, seed: u64) -> Self {
+        Self { seed, ..self }
+    }
+
+    /// Whether `input` is valid for the reference and the solutions disagree.
+    fn disagree(&self, input: &str) -> Option<(Outcome<O>, Outcome<O>)> {
+        let reference = outcome(self.reference, input);
+        let optimized = outcome(self.optimized, input);
+        (reference.is_ok() && reference != optimized).then_some((reference, optimized))
+    }
+
+    pub fn run(&self) -> Result<(), Counterexample<O>> {
+        for case in 0..self.cases {
+            let seed = self.seed + case as u64;
+            let size = 1 + case % self.max_size;
+            let input = (self.generate)(size, &mut Rng::new(seed));
+            if self.disagree(&input).is_some() {
+                return Err(self.shrink(seed, size, input));
+            }
+        }
+        Ok(())
+    }
+
+    /// Panics with the shrunk counterexample if the solutions disagree.
+    pub fn assert(&self) {
+        if let Err(counterexample) = self.run() {
+            panic!("{}", counterexample);
+        }
+    }
+
+    fn shrink(&self, seed: u64, size: usize, input: String) -> Counterexample<O> {
+        let (size, input) = (1..size)
+            .map(|size| (size, (self.generate)(size, &mut Rng::new(seed))))
+            .find(|(_, input)| self.disagree(input).is_some())
+            .unwrap_or((size, input));
+
+        let input = shrink_lines(&input, |input| self.disagree(input).is_some());
+        let (reference, optimized) = self
+            .disagree(&input)
+            .expect("shrinking keeps the disagreement");
+        Counterexample {
+            seed,
+            size,
+            input,
+            reference,
+            optimized,
+        }
+    }
+}
+
+/// Deletes lines from `input` while it still `fails`, first in large chunks and then
+/// in ever smaller ones. The trailing newline, if any, is kept.
+pub fn shrink_lines(input: &str, mut fails: impl FnMut(&str) -> bool) -> String {
+    let join = |lines: &[&str]| {
+        let mut joined = lines.join("\n");
+        if input.ends_with('\n') {
+            joined.push('\n');
+        }
+        joined
+    };
+
+    let mut lines = input.lines().collect::<Vec<_>>();
+    let mut chunk = lines.len() / 2;
+    while chunk > 0 {
+        let mut i = 0;
+        let mut shrunk = false;
+        while i + chunk <= lines.len() && lines.len() > chunk {
+            let candidate = [&lines[..i], &lines[i + chunk..]].concat();
+            if fails(&join(&candidate)) {
+                lines = candidate;
+                shrunk = true;
+            } else {
+                i += chunk;
+            }
+        }
+        if !shrunk {
+            chunk /= 2;
+        }
+    }
+    join(&lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One number per line.
+    fn numbers(size: usize, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| format!("{}\n", rng.range(0..100)))
+            .collect()
+    }
+
+    fn sum(input: &str) -> u32 {
+        input.lines().map(|line| line.parse::<u32>().unwrap()).sum()
+    }
+
+    /// Wrong as soon as a line holds a number above 90.
+    fn capped_sum(input: &str) -> u32 {
+        input
+            .lines()
+            .map(|line| line.parse::<u32>().unwrap().min(90))
+            .sum()
+    }
+
+    #[test]
+    fn test_agreeing() {
+        Differential::new(numbers, sum, |input| {
+            input.lines().map(|l| l.parse::<u32>().unwrap()).rev().sum()
+        })
+        .assert();
+    }
+
+    #[test]
+    fn test_shrinking() {
+        let counterexample = Differential::new(numbers, sum, capped_sum)
+            .run()
+            .unwrap_err();
+        assert_eq!(counterexample.input.lines().count(), 1);
+        let n = counterexample.input.trim().parse::<u32>().unwrap();
+        assert!(n > 90);
+        assert_eq!(counterexample.reference, Ok(n));
+        assert_eq!(counterexample.optimized, Ok(90));
+    }
+}
@@ -0,0 +1,149 @@
+//! Explanations of how an answer came about, printed after it with `--explain`.
+//!
+//! Solutions record [`table`]s and [`text`] blocks, e.g. which hands scored what.
+//! Nothing is recorded unless the binary was started with `--explain`, e.g.
+//! `cargo solve 07 -- --explain`: the closures that build an entry are not called,
+//! so leaving them in costs a flag check in `cargo time` or benchmarks. Titles are
+//! `Display`, so pass `format_args!` rather than `&format!` to keep them lazy.
+//! `solve!` prints what a part recorded after its answer.
+use crate::{ANSI_BOLD, ANSI_RESET};
+use std::fmt::Display;
+use std::sync::{Mutex, OnceLock};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Entry {
+    /// Free-form text, e.g. a grid.
+    Text { title: String, body: String },
+    /// Rows of cells under a header, printed with aligned columns.
+    Table {
+        title: String,
+        header: Vec<String>,
+        rows: Vec<Vec<String>>,
+    },
+}
+
+static ENTRIES: Mutex<Vec<Entry>> = Mutex::new(Vec::new());
+
+/// Whether `--explain` was passed on the command line.
+pub fn enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| std::env::args().skip(1).any(|arg| arg == "--explain"))
+}
+
+fn record(entry: impl FnOnce() -> Entry) {
+    if enabled() {
+        let entry = entry();
+        ENTRIES.lock().unwrap().push(entry);
+    }
+}
+
+/// Records the text built by `body` under `title`.
+pub fn text(title: impl Display, body: impl FnOnce() -> String) {
+    record(|| Entry::Text {
+        title: title.to_string(),
+        body: body(),
+    });
+}
+
+/// Records a table with the rows built by `rows`.
+pub fn table<R, C>(title: impl Display, header: &[&str], rows: impl FnOnce() -> R)
+where
+    R: IntoIterator<Item = Vec<C>>,
+    C: ToString,
+{
+    record(|| Entry::Table {
+        title: title.to_string(),
+        header: header.iter().map(|h| h.to_string()).collect(),
+        rows: rows()
+            .into_iter()
+            .map(|row| row.iter().map(C::to_string).collect())
+            .collect(),
+    });
+}
+
+/// The entries recorded since the last call, oldest first.
+pub fn take() -> Vec<Entry> {
+    std::mem::take(&mut *ENTRIES.lock().unwrap())
+}
+
+/// Formats `entries` for the terminal.
+pub fn render(entries: &[Entry]) -> String {
+    let mut out = String::new();
+    for entry in entries {
+        match entry {
+            Entry::Text { title, body } => {
+                out += &format!("{}{}{}\n", ANSI_BOLD, title, ANSI_RESET);
+                out += body.trim_end_matches('\n');
+                out.push('\n');
+            }
+            Entry::Table {
+                title,
+                header,
+                rows,
+            } => {
+                out += &format!("{}{}{}\n", ANSI_BOLD, title, ANSI_RESET);
+                let mut widths = header.iter().map(|h| h.len()).collect::<Vec<_>>();
+                for row in rows {
+                    for (i, cell) in row.iter().enumerate() {
+                        match widths.get_mut(i) {
+                            Some(width) => *width = (*width).max(cell.len()),
+                            None => widths.push(cell.len()),
+                        }
+                    }
+                }
+                // numbers are right-aligned, anything else left-aligned.
+                let numeric = (0..widths.len())
+                    .map(|i| {
+                        rows.iter()
+                            .filter_map(|row| row.get(i))
+                            .all(|cell| cell.parse::<f64>().is_ok())
+                    })
+                    .collect::<Vec<_>>();
+                for row in std::iter::once(header).chain(rows) {
+                    let cells = row
+                        .iter()
+                        .zip(widths.iter().zip(&numeric))
+                        .map(|(cell, (&width, &numeric))| {
+                            if numeric {
+                                format!("{:>width$}", cell)
+                            } else {
+                                format!("{:<width$}", cell)
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    out += cells.join("  ").trim_end();
+                    out.push('\n');
+                }
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let entries = [
+            Entry::Table {
+                title: "hands".to_string(),
+                header: vec!["hand".to_string(), "bid".to_string()],
+                rows: vec![
+                    vec!["32T3K".to_string(), "765".to_string()],
+                    vec!["QQQJA".to_string(), "1".to_string()],
+                ],
+            },
+            Entry::Text {
+                title: "grid".to_string(),
+                body: "#.\n.#\n".to_string(),
+            },
+        ];
+        let rendered = crate::markdown::strip_ansi(&render(&entries));
+        assert_eq!(
+            rendered,
+            "hands\nhand   bid\n32T3K  765\nQQQJA    1\ngrid\n#.\n.#\n"
+        );
+    }
+}
@@ -0,0 +1,277 @@
+//! A built-in random fuzzer for a day's parser and solvers.
+//!
+//! Every day's binary has a `main` for the `fuzz` feature (see
+//! [`main!`](crate::main)) that runs both parts on an input and discards the
+//! answers:
+//!
+//! ```ignore
+//! advent_of_code::helpers::fuzz::run(16, |input| {
+//!     let _ = part_one(input);
+//!     let _ = part_two(input);
+//! });
+//! ```
+//!
+//! `cargo fuzz-day 16` mutates the day's examples (`src/examples/16*.txt`) line-
+//! and byte-wise and reports the first input that panics, overflows (it runs
+//! without `--release`, so overflow checks are on) or takes longer than the
+//! timeout. Crashing inputs are shrunk and saved to `fuzz/artifacts/day16/`.
+//! Options go after `--`: `--runs N` (default 100000), `--seed S` and
+//! `--timeout-ms T` (default 1000).
+//!
+//! The same targets run under libFuzzer from the `fuzz/` crate, which calls
+//! [`days`](crate::days) directly. Pass the examples as a seed corpus:
+//! `cargo fuzz run day16 fuzz/corpus/day16 src/examples`.
+use super::differential::shrink_lines;
+use super::generate::Rng;
+use crate::runner::panic_message;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::process;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub type Target = fn(&str);
+
+/// Numbers that tend to break parsing and arithmetic.
+const EXTREMES: [&str; 7] = [
+    "0",
+    "-1",
+    "255",
+    "65536",
+    "4294967296",
+    "-9223372036854775808",
+    "99999999999999999999999",
+];
+
+/// Bytes that are likely to be syntax in some puzzle.
+const PUNCTUATION: &[u8] = b"\n \t,:;=-+()[]{}<>#.?/\\|";
+
+struct Args {
+    runs: u64,
+    seed: u64,
+    timeout: Duration,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        runs: args.opt_value_from_str("--runs")?.unwrap_or(100_000),
+        seed: args.opt_value_from_str("--seed")?.unwrap_or(0),
+        timeout: Duration::from_millis(args.opt_value_from_str("--timeout-ms")?.unwrap_or(1000)),
+    })
+}
+
+/// The day's examples, which seed the mutations.
+pub fn corpus(day: u8) -> Vec<String> {
+    let prefix = format!("{:02}", day);
+    let mut paths = fs::read_dir(Path::new("src").join("examples"))
+        .map(|dir| {
+            dir.filter_map(|entry| Some(entry.ok()?.path()))
+                .filter(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with(&prefix) && name.ends_with(".txt"))
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    paths.sort();
+    paths
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .map(|input| input.replace("\r\n", "\n"))
+        .collect()
+}
+
+/// Applies one to four random edits to `input`.
+pub fn mutate(input: &str, rng: &mut Rng) -> String {
+    let mut bytes = input.as_bytes().to_vec();
+    let alphabet = {
+        let mut alphabet = bytes.clone();
+        alphabet.extend_from_slice(PUNCTUATION);
+        alphabet.extend_from_slice(b"0123456789");
+        alphabet
+    };
+
+    for _ in 0..rng.range(1..5) {
+        let len = bytes.len() as i64;
+        let at = rng.range(0..len + 1) as usize;
+        match rng.below(8) {
+            0 if at < bytes.len() => bytes[at] = *rng.choose(&alphabet),
+            1 => bytes.insert(at, *rng.choose(&alphabet)),
+            2 if at < bytes.len() => {
+                let end = (at + rng.range(1..9) as usize).min(bytes.len());
+                bytes.drain(at..end);
+            }
+            3 => bytes.truncate(at),
+            4..=6 => {
+                let mut lines = bytes
+                    .split(|&b| b == b'\n')
+                    .map(<[u8]>::to_vec)
+                    .collect::<Vec<_>>();
+                let i = rng.below(lines.len() as u64) as usize;
+                match rng.below(3) {
+                    0 => lines.insert(i, lines[i].clone()),
+                    1 => {
+                        lines.remove(i);
+                    }
+                    _ => {
+                        let j = rng.below(lines.len() as u64) as usize;
+                        lines.swap(i, j);
+                    }
+                }
+                bytes = lines.join(&b'\n');
+            }
+            _ => {
+                // swap a number for an extreme one.
+                let starts = (0..bytes.len())
+                    .filter(|&i| {
+                        bytes[i].is_ascii_digit() && (i == 0 || !bytes[i - 1].is_ascii_digit())
+                    })
+                    .collect::<Vec<_>>();
+                if let Some(&start) = starts.get(rng.below(starts.len().max(1) as u64) as usize) {
+                    let end = (start..bytes.len())
+                        .find(|&i| !bytes[i].is_ascii_digit())
+                        .unwrap_or(bytes.len());
+                    bytes.splice(start..end, rng.choose(&EXTREMES).bytes());
+                }
+            }
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// The panic message if `target` panics on `input`.
+pub fn check(target: Target, input: &str) -> Result<(), String> {
+    panic::catch_unwind(AssertUnwindSafe(|| target(input)))
+        .map_err(|payload| panic_message(&*payload))
+}
+
+/// Deletes lines, then single bytes, while `target` still panics.
+pub fn shrink(target: Target, input: &str) -> String {
+    let fails = |input: &str| check(target, input).is_err();
+    let mut input = shrink_lines(input, fails);
+    let mut i = 0;
+    while i < input.len() {
+        if !input.is_char_boundary(i) {
+            i += 1;
+            continue;
+        }
+        let mut candidate = input.clone();
+        candidate.remove(i);
+        if fails(&candidate) {
+            input = candidate;
+        } else {
+            i += input[i..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    input
+}
+
+fn save_artifact(day: u8, kind: &str, input: &str) -> String {
+    let dir = Path::new("fuzz")
+        .join("artifacts")
+        .join(format!("day{:02}", day));
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    let path = dir.join(format!("{}-{:016x}.txt", kind, hash));
+    match fs::create_dir_all(&dir).and_then(|_| fs::write(&path, input)) {
+        Ok(()) => path.display().to_string(),
+        Err(e) => format!("(not saved: {})", e),
+    }
+}
+
+/// Fuzzes `target` with mutations of the day's examples; see the module docs.
+pub fn run(day: u8, target: Target) {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    let corpus = corpus(day);
+    if corpus.is_empty() {
+        eprintln!("No examples for day {:02} to start from.", day);
+        process::exit(1);
+    }
+
+    // the watchdog reports and exits if a single run exceeds the timeout.
+    let current = Arc::new(Mutex::new((Instant::now(), None::<String>)));
+    {
+        let current = Arc::clone(&current);
+        let timeout = args.timeout;
+        thread::spawn(move || loop {
+            thread::sleep(timeout / 10);
+            let (started, input) = &*current.lock().unwrap();
+            if let Some(input) = input.as_ref().filter(|_| started.elapsed() > timeout) {
+                eprintln!("Timeout after {:?} on:\n{}", timeout, input);
+                eprintln!("saved to {}", save_artifact(day, "timeout", input));
+                process::exit(1);
+            }
+        });
+    }
+
+    // panics are expected; only report them once shrunk.
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut rng = Rng::new(args.seed);
+    let inputs = corpus.iter().cloned().chain((0..args.runs).map(|_| {
+        let seed = &corpus[rng.below(corpus.len() as u64) as usize];
+        mutate(seed, &mut rng)
+    }));
+    for (run, input) in inputs.enumerate() {
+        *current.lock().unwrap() = (Instant::now(), Some(input.clone()));
+        if check(target, &input).is_err() {
+            *current.lock().unwrap() = (Instant::now(), None);
+            let input = shrink(target, &input);
+            panic::set_hook(default_hook);
+            eprintln!("Crash after {} runs on:\n{}\n", run + 1, input);
+            eprintln!("saved to {}", save_artifact(day, "crash", &input));
+            // rerun with the default hook to show where it panics.
+            let _ = check(target, &input);
+            process::exit(1);
+        }
+    }
+    *current.lock().unwrap() = (Instant::now(), None);
+    println!(
+        "Day {:02}: {} runs without crashes.",
+        day,
+        corpus.len() as u64 + args.runs
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fragile(input: &str) {
+        for line in input.lines() {
+            let _: u8 = line.parse().unwrap_or(0);
+            assert!(!line.contains('!'), "exclamation");
+        }
+    }
+
+    #[test]
+    fn test_mutate() {
+        let mut rng = Rng::new(3);
+        let input = "1 2 3\n4 5 6\n";
+        let mutated = (0..100)
+            .map(|_| mutate(input, &mut rng))
+            .collect::<Vec<_>>();
+        assert!(mutated.iter().any(|m| m != input));
+        assert!(mutated.iter().any(|m| m.lines().count() != 2));
+    }
+
+    #[test]
+    fn test_shrink() {
+        assert_eq!(check(fragile, "1\n2\n"), Ok(()));
+        let shrunk = shrink(fragile, "1\nab!c\n3\n");
+        assert_eq!(shrunk, "!");
+        assert_eq!(check(fragile, &shrunk), Err("exclamation".to_string()));
+    }
+}
@@ -0,0 +1,106 @@
+//! Seeded generators for puzzle inputs of arbitrary size.
+//!
+//! A day opts in by defining `generate(size, rng) -> String` and marking it with
+//! `(generate)` in the list in `src/days.rs`, which puts it in its
+//! [`Day`](crate::runner::Day):
+//!
+//! ```ignore
+//! // src/days/day11.rs
+//! pub fn generate(size: usize, rng: &mut crate::helpers::generate::Rng) -> String {
+//!     ...
+//! }
+//!
+//! // src/days.rs
+//! days! {
+//!     11 => day11 (generate),
+//! }
+//! ```
+//!
+//! `cargo gen NN --size N --seed S` then prints an input to stdout. What `size`
+//! means (lines, grid side, ...) is up to the day, but it should produce `size`
+//! lines that both parts solve.
+use std::ops::Range;
+
+pub const DEFAULT_SIZE: usize = 100;
+
+/// A day's `generate`.
+pub type Generator = fn(usize, &mut Rng) -> String;
+
+/// SplitMix64. Unlike `rand`'s generators, its output for a seed never changes,
+/// so seeds stay reproducible.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end, "empty range");
+        range.start + self.below(range.end.abs_diff(range.start)) as i64
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i as u64 + 1) as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(1234567);
+        assert_eq!(rng.next_u64(), 6457827717110365317);
+        assert_eq!(rng.next_u64(), 3203168211198807973);
+
+        let mut rng = Rng::new(7);
+        assert!((0..1000).all(|_| (-3..4).contains(&rng.range(-3..4))));
+        let mut items = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+        assert_eq!(Rng::new(7).next_u64(), Rng::new(7).next_u64());
+    }
+
+    #[test]
+    fn test_generators() {
+        for day in crate::days::ALL {
+            let Some(generate) = day.generate else {
+                continue;
+            };
+            let input = generate(50, &mut Rng::new(1));
+            assert_eq!(input.lines().count(), 50, "day {}", day.day);
+            for (part, solver) in (1..).zip(day.parts) {
+                assert!(solver(&input).is_some(), "day {} part {}", day.day, part);
+            }
+        }
+    }
+}
@@ -0,0 +1,291 @@
+//! Half-open integer intervals, normalized interval sets and n-dimensional boxes.
+use super::math::Integer;
+
+/// The half-open interval `start..end`. Empty when `end <= start`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Integer> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// The interval of `len` values beginning at `start`.
+    pub fn from_len(start: T, len: T) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::ZERO
+        } else {
+            self.end - self.start
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The overlap of both intervals, which may be empty.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Splits into the values below `at` and the values from `at` onwards.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let at = at.clamp(self.start, self.end.max(self.start));
+        (Self::new(self.start, at), Self::new(at, self.end))
+    }
+
+    pub fn shift(&self, delta: T) -> Self {
+        Self::new(self.start + delta, self.end + delta)
+    }
+}
+
+/// A set of integers stored as sorted, disjoint, non-adjacent, non-empty intervals.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    fn normalize(mut intervals: Vec<Interval<T>>) -> Self {
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort_unstable_by_key(|interval| interval.start);
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        Self { intervals: merged }
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = Self::normalize(intervals);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> T {
+        self.iter()
+            .fold(T::ZERO, |acc, interval| acc + interval.len())
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|interval| interval.end - T::ONE)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self
+            .intervals
+            .partition_point(|interval| interval.end <= value);
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalize(self.iter().chain(other.iter()).copied().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            let overlap = a.intersection(&b);
+            if !overlap.is_empty() {
+                intervals.push(overlap);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        // pieces of disjoint, sorted inputs are already normalized.
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let mut j = 0;
+        for &interval in self.iter() {
+            let mut rest = interval;
+            while j < other.intervals.len() && other.intervals[j].end <= rest.start {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.intervals.len() && other.intervals[k].start < rest.end {
+                let (below, _) = rest.split_at(other.intervals[k].start);
+                let (_, above) = rest.split_at(other.intervals[k].end);
+                if !below.is_empty() {
+                    intervals.push(below);
+                }
+                rest = above;
+                k += 1;
+            }
+            if !rest.is_empty() {
+                intervals.push(rest);
+            }
+        }
+        Self { intervals }
+    }
+
+    pub fn shift(&self, delta: T) -> Self {
+        Self {
+            intervals: self.iter().map(|interval| interval.shift(delta)).collect(),
+        }
+    }
+}
+
+impl<T: Integer> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self::normalize(vec![interval])
+    }
+}
+
+impl<T: Integer> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        Self::normalize(iter.into_iter().collect())
+    }
+}
+
+/// The cartesian product of `N` intervals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntervalBox<T, const N: usize> {
+    pub axes: [Interval<T>; N],
+}
+
+impl<T: Integer, const N: usize> IntervalBox<T, N> {
+    pub fn new(axes: [Interval<T>; N]) -> Self {
+        Self { axes }
+    }
+
+    /// The number of integer points in the box.
+    pub fn volume(&self) -> T {
+        self.axes
+            .iter()
+            .fold(T::ONE, |acc, interval| acc * interval.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(Interval::is_empty)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut axes = self.axes;
+        for (axis, other) in axes.iter_mut().zip(other.axes.iter()) {
+            *axis = axis.intersection(other);
+        }
+        Self { axes }
+    }
+
+    /// Splits along `axis` into the part below `at` and the part from `at` onwards.
+    pub fn split_at(&self, axis: usize, at: T) -> (Self, Self) {
+        let (below, above) = self.axes[axis].split_at(at);
+        let (mut lower, mut upper) = (*self, *self);
+        lower.axes[axis] = below;
+        upper.axes[axis] = above;
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet<i64> {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let interval = Interval::from_len(5u64, 3);
+        assert_eq!(interval, Interval::new(5, 8));
+        assert_eq!(interval.len(), 3);
+        assert!(interval.contains(7) && !interval.contains(8));
+        assert_eq!(Interval::new(8, 5u64).len(), 0);
+        assert_eq!(
+            interval.split_at(6),
+            (Interval::new(5, 6), Interval::new(6, 8))
+        );
+        assert_eq!(
+            interval.split_at(0),
+            (Interval::new(5, 5), Interval::new(5, 8))
+        );
+        assert!(interval.intersection(&Interval::new(8, 10)).is_empty());
+    }
+
+    #[test]
+    fn test_normalization() {
+        let set = set(&[(5, 7), (0, 2), (2, 3), (6, 9), (4, 4)]);
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            vec![Interval::new(0, 3), Interval::new(5, 9)]
+        );
+        assert_eq!(set.len(), 7);
+        assert_eq!((set.min(), set.max()), (Some(0), Some(8)));
+        assert!(set.contains(2) && !set.contains(3) && set.contains(5));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (28, 40)]);
+        assert_eq!(a.union(&b), set(&[(0, 40)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25), (28, 30)]));
+        assert_eq!(a.difference(&b), set(&[(0, 5), (25, 28)]));
+        assert_eq!(b.difference(&a), set(&[(10, 20), (30, 40)]));
+        assert_eq!(a.difference(&a), IntervalSet::new());
+        assert_eq!(a.shift(-5), set(&[(-5, 5), (15, 25)]));
+    }
+
+    #[test]
+    fn test_box() {
+        let cube = IntervalBox::new([Interval::new(1, 11); 3]);
+        assert_eq!(cube.volume(), 1000);
+        let (lower, upper) = cube.split_at(1, 4);
+        assert_eq!((lower.volume(), upper.volume()), (300, 700));
+        assert!(cube.split_at(0, 1).0.is_empty());
+        assert_eq!(lower.intersection(&upper).volume(), 0);
+    }
+}
@@ -0,0 +1,43 @@
+//! Leveled events and spans via `tracing`.
+//!
+//! Solutions log with `tracing::{debug, trace, ...}` and mark work with spans;
+//! `solve!` wraps each part in a `part` span. Output goes to stderr and is off
+//! below `warn` unless enabled:
+//!
+//! - `AOC_LOG=debug cargo solve 16` takes any `EnvFilter` directive, e.g.
+//!   `AOC_LOG=16=trace` for a single day.
+//! - `cargo solve 16 -- --verbose` is shorthand for `AOC_LOG=debug`.
+//!
+//! Release builds (`cargo time`, `cargo all`) compile everything below `warn`
+//! out, so benchmark timings don't pay for it. In tests, call [`init_test`] to
+//! route events through the test harness, which only prints them for failing
+//! tests; the example tests generated by [`example!`](crate::example) do.
+use tracing_subscriber::EnvFilter;
+
+pub const ENV_VAR: &str = "AOC_LOG";
+
+fn filter() -> EnvFilter {
+    if let Ok(directives) = std::env::var(ENV_VAR) {
+        return EnvFilter::new(directives);
+    }
+    let verbose = std::env::args().skip(1).any(|arg| arg == "--verbose");
+    EnvFilter::new(if verbose { "debug" } else { "warn" })
+}
+
+/// Installs the stderr subscriber. Calling it again does nothing.
+pub fn init() {
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter())
+        .with_writer(std::io::stderr)
+        .without_time()
+        .try_init();
+}
+
+/// Installs a subscriber that writes through the test harness's output capture.
+pub fn init_test() {
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter())
+        .with_test_writer()
+        .without_time()
+        .try_init();
+}
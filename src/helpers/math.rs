@@ -0,0 +1,217 @@
+//! Number theory over the primitive integer types.
+//!
+//! Everything here is generic over [`Integer`], which is implemented for all of
+//! `u8..=u128`, `i8..=i128`, `usize` and `isize`. Signed-only operations (extended
+//! gcd, modular inverse) additionally require [`Signed`].
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+pub trait Integer:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Absolute value; the identity for unsigned types.
+    fn abs(self) -> Self;
+}
+
+pub trait Signed: Integer + Neg<Output = Self> {}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                self
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+        }
+
+        impl Signed for $t {}
+    )*};
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+/// Greatest common divisor, always non-negative. `gcd(0, 0) == 0`.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple, always non-negative. Zero if either argument is zero.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`.
+pub fn extended_gcd<T: Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (T::ONE, T::ZERO);
+    let (mut old_t, mut t) = (T::ZERO, T::ONE);
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+        (old_t, t) = (t, old_t - q * t);
+    }
+    if old_r < T::ZERO {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
+
+/// The inverse of `a` modulo `m` in `0..m`, if `a` and `m` are coprime.
+pub fn mod_inverse<T: Signed>(a: T, m: T) -> Option<T> {
+    let m = m.abs();
+    let (g, x, _) = extended_gcd(a % m, m);
+    if g != T::ONE {
+        return None;
+    }
+    Some(((x % m) + m) % m)
+}
+
+/// Solves the system `x ≡ r (mod m)` for every `(r, m)` pair, returning
+/// `(x, lcm)` with `x` in `0..lcm`. The moduli need not be coprime; `None` is
+/// returned when the congruences contradict each other or the result does not fit
+/// in `T`. Intermediate products are computed in `i128`.
+pub fn crt<T>(congruences: impl IntoIterator<Item = (T, T)>) -> Option<(T, T)>
+where
+    T: Copy + Into<i128> + TryFrom<i128>,
+{
+    let (mut x, mut m) = (0i128, 1i128);
+    for (r, n) in congruences {
+        let (r, n) = (r.into(), n.into().abs());
+        if n == 0 {
+            return None;
+        }
+        let (g, p, _) = extended_gcd(m, n);
+        let diff = r - x;
+        if diff % g != 0 {
+            return None;
+        }
+        let step = n / g;
+        let k = (diff / g)
+            .rem_euclid(step)
+            .checked_mul(p.rem_euclid(step))?
+            % step;
+        x = x.checked_add(m.checked_mul(k)?)?;
+        m = m.checked_mul(step)?;
+        x = x.rem_euclid(m);
+    }
+    Some((T::try_from(x).ok()?, T::try_from(m).ok()?))
+}
+
+/// Prime factorization by trial division as `(prime, exponent)` pairs in increasing
+/// order. Values below 2 have no factors; negative values are factored by magnitude.
+pub fn factorize<T: Integer>(n: T) -> Vec<(T, u32)> {
+    let two = T::ONE + T::ONE;
+    let mut n = n.abs();
+    let mut factors = Vec::new();
+    let mut p = two;
+    while p <= n / p {
+        let mut exp = 0;
+        while n % p == T::ZERO {
+            n = n / p;
+            exp += 1;
+        }
+        if exp > 0 {
+            factors.push((p, exp));
+        }
+        p = if p == two { p + T::ONE } else { p + two };
+    }
+    if n > T::ONE {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12u32, 18), 6);
+        assert_eq!(gcd(0u8, 7), 7);
+        assert_eq!(gcd(7i64, 0), 7);
+        assert_eq!(gcd(0usize, 0), 0);
+        assert_eq!(gcd(-12i32, 18), 6);
+        assert_eq!(lcm(4u64, 6), 12);
+        assert_eq!(lcm(0u64, 6), 0);
+        assert_eq!(lcm(-4i16, 6), 12);
+        // a repeated prime factor must not be collapsed.
+        assert_eq!([4u64, 8, 6].into_iter().fold(1, lcm), 24);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240i64, 46), (46, 240), (-7, 3), (0, 5), (5, 0)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+        assert_eq!(mod_inverse(3i32, 11), Some(4));
+        assert_eq!(mod_inverse(-3i32, 11), Some(7));
+        assert_eq!(mod_inverse(6i32, 9), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2u64, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // non-coprime moduli
+        assert_eq!(crt([(2i64, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1i64, 4), (2, 6)]), None);
+        assert_eq!(crt(Vec::<(i32, i32)>::new()), Some((0, 1)));
+    }
+
+    #[test]
+    fn test_factorize() {
+        assert_eq!(factorize(360u32), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factorize(97u8), vec![(97, 1)]);
+        assert_eq!(factorize(1i32), vec![]);
+        assert_eq!(factorize(-12i32), vec![(2, 2), (3, 1)]);
+        assert_eq!(
+            factorize(u64::MAX),
+            vec![
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65537, 1),
+                (6700417, 1)
+            ]
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! Memoization for recursive functions that borrow the puzzle input.
+//!
+//! The cache is passed down the recursion instead of living in a static, so keys
+//! only need to identify a sub-problem within the current call tree (e.g. the
+//! remaining lengths of borrowed slices). Call [`Memo::clear`] before solving an
+//! unrelated problem, such as the next input line.
+//!
+//! ```
+//! use advent_of_code::helpers::memo::Memo;
+//!
+//! fn fib(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+//!     memo.get_or_insert_with(n, |memo| {
+//!         if n < 2 {
+//!             n
+//!         } else {
+//!             fib(n - 1, memo) + fib(n - 2, memo)
+//!         }
+//!     })
+//! }
+//!
+//! assert_eq!(fib(90, &mut Memo::new()), 2880067194370816120);
+//! ```
+use cached::{Cached, UnboundCache};
+use std::hash::Hash;
+
+pub struct Memo<K: Hash + Eq, V> {
+    name: &'static str,
+    cache: UnboundCache<K, V>,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::named("memo")
+    }
+
+    /// A cache whose hit/miss report (a `debug` event when dropped) carries `name`.
+    pub fn named(name: &'static str) -> Self {
+        Self {
+            name,
+            cache: UnboundCache::new(),
+        }
+    }
+
+    /// The cached value for `key`, computing it with `f` on a miss. `f` gets the
+    /// cache back so it can recurse.
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.cache_get(&key) {
+            return value.clone();
+        }
+        let value = f(self);
+        self.cache.cache_set(key, value.clone());
+        value
+    }
+
+    /// Forgets all values but keeps counting hits and misses.
+    pub fn clear(&mut self) {
+        self.cache.cache_clear();
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.cache.cache_hits().unwrap_or_default()
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.cache.cache_misses().unwrap_or_default()
+    }
+}
+
+impl<K: Hash + Eq, V> Drop for Memo<K, V> {
+    fn drop(&mut self) {
+        let hits = self.cache.cache_hits().unwrap_or_default();
+        let misses = self.cache.cache_misses().unwrap_or_default();
+        if hits + misses > 0 {
+            tracing::debug!(
+                "{}: {} hits, {} misses ({:.1}% hit rate)",
+                self.name,
+                hits,
+                misses,
+                100.0 * hits as f64 / (hits + misses) as f64
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(x: usize, y: usize, memo: &mut Memo<(usize, usize), u64>) -> u64 {
+        memo.get_or_insert_with((x, y), |memo| {
+            if x == 0 || y == 0 {
+                1
+            } else {
+                paths(x - 1, y, memo) + paths(x, y - 1, memo)
+            }
+        })
+    }
+
+    #[test]
+    fn test_memo() {
+        let mut memo = Memo::new();
+        assert_eq!(paths(16, 16, &mut memo), 601080390);
+        // every (x, y) in 0..=16 except (0, 0), which is never reached.
+        assert_eq!(memo.misses(), 17 * 17 - 1);
+        assert_eq!(paths(16, 16, &mut memo), 601080390);
+        assert_eq!(memo.misses(), 17 * 17 - 1);
+
+        let hits = memo.hits();
+        memo.clear();
+        assert_eq!(paths(2, 2, &mut memo), 6);
+        assert_eq!(memo.misses(), 17 * 17 - 1 + 8);
+        assert!(memo.hits() > hits);
+    }
+}
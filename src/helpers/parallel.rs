@@ -0,0 +1,67 @@
+//! Iterators that run on rayon's thread pool with the `parallel` feature and
+//! serially without it, so solutions are written once for both builds.
+//!
+//! Import everything with `use advent_of_code::helpers::parallel::*;` and call
+//! `into_maybe_par_iter()` where `into_par_iter()` would go. Only adapters that
+//! exist on both `Iterator` and rayon's `ParallelIterator` (plus `map_init`)
+//! should be chained after it.
+#[cfg(feature = "parallel")]
+pub use rayon::prelude::*;
+
+#[cfg(feature = "parallel")]
+pub trait IntoMaybeParallelIterator: IntoParallelIterator + Sized {
+    fn into_maybe_par_iter(self) -> Self::Iter {
+        self.into_par_iter()
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<I: IntoParallelIterator> IntoMaybeParallelIterator for I {}
+
+#[cfg(not(feature = "parallel"))]
+pub trait IntoMaybeParallelIterator: IntoIterator + Sized {
+    fn into_maybe_par_iter(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<I: IntoIterator> IntoMaybeParallelIterator for I {}
+
+/// Serial stand-in for rayon's `map_init`: `init` runs once, and the state is
+/// reused for every item.
+#[cfg(not(feature = "parallel"))]
+pub trait MapInit: Iterator + Sized {
+    fn map_init<T, R, INIT, F>(self, init: INIT, map_op: F) -> impl Iterator<Item = R>
+    where
+        INIT: Fn() -> T,
+        F: Fn(&mut T, Self::Item) -> R,
+    {
+        let mut state = init();
+        self.map(move |item| map_op(&mut state, item))
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<I: Iterator> MapInit for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_maybe_parallel() {
+        let squares: u64 = (0..1000u64).into_maybe_par_iter().map(|x| x * x).sum();
+        assert_eq!(squares, 332833500);
+
+        let lens = vec!["a", "bb", "ccc"]
+            .into_maybe_par_iter()
+            .map_init(String::new, |buf, s| {
+                buf.clear();
+                buf.push_str(s);
+                buf.len()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(lens, vec![1, 2, 3]);
+    }
+}
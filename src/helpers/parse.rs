@@ -0,0 +1,331 @@
+//! Input parsing that reports where it failed instead of panicking.
+//!
+//! Example: `let (name, rules) = advent_of_code::scan!(line, "{}{{{}}}" => &str, &str)?;`
+use std::fmt;
+
+/// A parse failure at a 1-based line and column. `line` is unknown until the
+/// error passes through [`parse_lines`] or [`ParseError::at_line`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: Option<usize>,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Sets the line unless it is already known, e.g. from a nested block.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Moves the error down by `lines`, for errors from a block that does not
+    /// start at the top of the input.
+    pub fn below(mut self, lines: usize) -> Self {
+        if let Some(line) = &mut self.line {
+            *line += lines;
+        }
+        self
+    }
+
+    /// Moves the error right by `columns`, for errors from a piece that does not
+    /// start at the beginning of the line.
+    pub fn right(mut self, columns: usize) -> Self {
+        self.column += columns;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}, column {}: {}", line, self.column, self.message),
+            None => write!(f, "column {}: {}", self.column, self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A value that can be read from a field captured by [`scan!`](crate::scan).
+pub trait Field<'a>: Sized {
+    fn from_field(s: &'a str) -> Result<Self, String>;
+}
+
+impl<'a> Field<'a> for &'a str {
+    fn from_field(s: &'a str) -> Result<Self, String> {
+        Ok(s)
+    }
+}
+
+macro_rules! impl_field {
+    ($($t:ty),*) => {$(
+        impl Field<'_> for $t {
+            fn from_field(s: &str) -> Result<Self, String> {
+                s.parse()
+                    .map_err(|e| format!("invalid {} `{}`: {}", stringify!($t), s, e))
+            }
+        }
+    )*};
+}
+
+impl_field!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_field!(f32, f64, bool, char, String);
+
+enum Token {
+    Literal(String),
+    Field,
+}
+
+fn tokenize(pattern: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut literal = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('{', Some('{')) | ('}', Some('}')) => {
+                chars.next();
+                literal.push(c);
+            }
+            ('{', Some('}')) => {
+                chars.next();
+                if !literal.is_empty() {
+                    tokens.push(Token::Literal(std::mem::take(&mut literal)));
+                }
+                tokens.push(Token::Field);
+            }
+            _ => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        tokens.push(Token::Literal(literal));
+    }
+    tokens
+}
+
+/// Matches `input` against `pattern`, where `{}` captures everything up to the
+/// following literal and `{{`/`}}` are literal braces. Returns each captured
+/// field with its 1-based column.
+pub fn scan<'a>(pattern: &str, input: &'a str) -> Result<Vec<(usize, &'a str)>, ParseError> {
+    let tokens = tokenize(pattern);
+    let mut fields = Vec::new();
+    let mut pos = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Literal(literal) => {
+                if !input[pos..].starts_with(literal.as_str()) {
+                    return Err(ParseError::new(pos + 1, format!("expected `{}`", literal)));
+                }
+                pos += literal.len();
+            }
+            Token::Field => {
+                let len = match tokens.get(i + 1) {
+                    Some(Token::Literal(next)) => input[pos..]
+                        .find(next.as_str())
+                        .ok_or_else(|| ParseError::new(pos + 1, format!("expected `{}`", next)))?,
+                    Some(Token::Field) => {
+                        return Err(ParseError::new(
+                            pos + 1,
+                            format!("ambiguous pattern `{}`: `{{}}{{}}`", pattern),
+                        ))
+                    }
+                    None => input.len() - pos,
+                };
+                fields.push((pos + 1, &input[pos..pos + len]));
+                pos += len;
+            }
+        }
+    }
+    if pos < input.len() {
+        return Err(ParseError::new(pos + 1, "unexpected trailing input"));
+    }
+    Ok(fields)
+}
+
+/// Converts one field captured by [`scan`].
+pub fn field<'a, T: Field<'a>>(field: Option<(usize, &'a str)>) -> Result<T, ParseError> {
+    let (column, s) = field.ok_or_else(|| ParseError::new(1, "pattern has too few fields"))?;
+    T::from_field(s).map_err(|message| ParseError::new(column, message))
+}
+
+/// Checks that [`scan`] converted every captured field, failing at the first one
+/// left over.
+pub fn no_more_fields<'a>(
+    mut fields: impl Iterator<Item = (usize, &'a str)>,
+) -> Result<(), ParseError> {
+    match fields.next() {
+        Some((column, _)) => Err(ParseError::new(column, "pattern has too many fields")),
+        None => Ok(()),
+    }
+}
+
+/// Matches a string against a pattern and converts the captured fields into a
+/// tuple of the given types, see [`scan`](crate::helpers::parse::scan).
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $pattern:literal => $($t:ty),+ $(,)?) => {{
+        use $crate::helpers::parse::{field, no_more_fields, scan};
+        scan($pattern, $input).and_then(|fields| {
+            let mut fields = fields.into_iter();
+            let tuple = ($(field::<$t>(fields.next())?,)+);
+            no_more_fields(fields)?;
+            Ok(tuple)
+        })
+    }};
+}
+
+/// Every integer in `line`, e.g. `[-3, 14]` for `"x=-3, y=14"`. A `-` only counts
+/// as a sign if `T` is signed.
+pub fn ints<'a, T: Field<'a>>(line: &'a str) -> Result<Vec<T>, ParseError> {
+    let bytes = line.as_bytes();
+    let mut ints = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = if i > 0 && bytes[i - 1] == b'-' && T::from_field("-0").is_ok() {
+            i - 1
+        } else {
+            i
+        };
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let value = T::from_field(&line[start..i])
+            .map_err(|message| ParseError::new(start + 1, message))?;
+        ints.push(value);
+    }
+    Ok(ints)
+}
+
+/// The blocks of lines separated by blank lines, each with the number of lines
+/// before it, so errors can be moved to their place with [`ParseError::below`].
+pub fn blocks(input: &str) -> impl Iterator<Item = (usize, &str)> + '_ {
+    let mut line = 0;
+    input.split("\n\n").filter_map(move |block| {
+        let start = line + block.len() - block.trim_start_matches('\n').len();
+        line += block.matches('\n').count() + 2;
+        let block = block.trim_matches('\n');
+        (!block.is_empty()).then_some((start, block))
+    })
+}
+
+/// Parses every line with `f`, attaching the line number to errors.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| f(line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+/// Parses a rectangular grid of ASCII cells, rejecting cells `f` does not accept.
+pub fn grid<T>(input: &str, mut f: impl FnMut(u8) -> Option<T>) -> Result<Vec<Vec<T>>, ParseError> {
+    let grid = parse_lines(input, |line| {
+        line.bytes()
+            .enumerate()
+            .map(|(x, c)| {
+                f(c).ok_or_else(|| ParseError::new(x + 1, format!("invalid cell {:?}", c as char)))
+            })
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+    if let Some(y) = grid.iter().position(|row| row.len() != grid[0].len()) {
+        let message = format!("expected {} cells, found {}", grid[0].len(), grid[y].len());
+        return Err(ParseError::new(1, message).at_line(y + 1));
+    }
+    Ok(grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(
+            crate::scan!("AAA = (BBB, CCC)", "{} = ({}, {})" => &str, &str, &str),
+            Ok(("AAA", "BBB", "CCC"))
+        );
+        assert_eq!(
+            crate::scan!("px{a<2006:qkq,rfg}", "{}{{{}}}" => String, &str),
+            Ok(("px".to_string(), "a<2006:qkq,rfg"))
+        );
+        assert_eq!(
+            crate::scan!("x=12,y=-3", "x={},y={}" => u8, i32),
+            Ok((12, -3))
+        );
+        assert_eq!(
+            crate::scan!("x=12;y=3", "x={},y={}" => u8, u8),
+            Err(ParseError::new(3, "expected `,y=`"))
+        );
+        assert_eq!(
+            crate::scan!("x=1a,y=3", "x={},y={}" => u8, u8),
+            Err(ParseError::new(
+                3,
+                "invalid u8 `1a`: invalid digit found in string"
+            ))
+        );
+        assert_eq!(
+            crate::scan!("x=1,y=3!", "x={},y={}!!" => u8, u8)
+                .unwrap_err()
+                .column,
+            7
+        );
+        assert_eq!(
+            crate::scan!("12", "{}{}" => u8, u8),
+            Err(ParseError::new(1, "ambiguous pattern `{}{}`: `{}{}`"))
+        );
+        assert_eq!(
+            crate::scan!("1 2 3", "{} {} {}" => u8, u8),
+            Err(ParseError::new(5, "pattern has too many fields"))
+        );
+        assert_eq!(
+            crate::scan!("1 2", "{} {}" => u8, u8, u8),
+            Err(ParseError::new(1, "pattern has too few fields"))
+        );
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<i32>("x=-3, y=14 z-5"), Ok(vec![-3, 14, -5]));
+        assert_eq!(ints::<u32>("x=-3, y=14"), Ok(vec![3, 14]));
+        assert_eq!(ints::<u64>("no numbers"), Ok(vec![]));
+        assert_eq!(ints::<u8>("1 300").unwrap_err().column, 3);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let blocks = blocks("a\nb\n\nc\n\n\nd\n").collect::<Vec<_>>();
+        assert_eq!(blocks, vec![(0, "a\nb"), (3, "c"), (6, "d")]);
+    }
+
+    #[test]
+    fn test_grid() {
+        let cell = |c| match c {
+            b'.' => Some(false),
+            b'#' => Some(true),
+            _ => None,
+        };
+        assert_eq!(
+            grid("#.\n.#", cell),
+            Ok(vec![vec![true, false], vec![false, true]])
+        );
+        let err = grid("#.\n.x", cell).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), 2));
+        assert_eq!(err.to_string(), "line 2, column 2: invalid cell 'x'");
+        assert_eq!(grid("#.\n.", cell).unwrap_err().line, Some(2));
+    }
+}
@@ -0,0 +1,101 @@
+//! Simple lattice polygons: shoelace area and Pick's theorem.
+use super::math::gcd;
+
+/// A closed polygon with integer vertices, listed in either winding order. The
+/// last vertex connects back to the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    pub vertices: Vec<[i64; 2]>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<[i64; 2]>) -> Self {
+        Self { vertices }
+    }
+
+    /// Traces a path from `[0, 0]`, moving `len` times by `dir` for each move.
+    pub fn from_moves(moves: impl IntoIterator<Item = ([i64; 2], i64)>) -> Self {
+        let mut pos = [0, 0];
+        let mut vertices = vec![pos];
+        for ([dx, dy], len) in moves {
+            pos = [pos[0] + dx * len, pos[1] + dy * len];
+            vertices.push(pos);
+        }
+        // a closed path ends where it started.
+        if vertices.len() > 1 && vertices.last() == vertices.first() {
+            vertices.pop();
+        }
+        Self { vertices }
+    }
+
+    fn edges(&self) -> impl Iterator<Item = ([i64; 2], [i64; 2])> + '_ {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    /// Twice the enclosed area, which is always an integer (shoelace formula).
+    pub fn double_area(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| a[0] * b[1] - a[1] * b[0])
+            .sum::<i64>()
+            .abs()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.double_area() as f64 / 2.0
+    }
+
+    /// The number of lattice points on the edges.
+    pub fn boundary_points(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| gcd(b[0] - a[0], b[1] - a[1]))
+            .sum()
+    }
+
+    /// The number of lattice points strictly inside (Pick's theorem).
+    pub fn interior_points(&self) -> i64 {
+        (self.double_area() - self.boundary_points() + 2) / 2
+    }
+
+    /// The number of lattice points inside or on the boundary.
+    pub fn enclosed_points(&self) -> i64 {
+        self.interior_points() + self.boundary_points()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_square() {
+        let square = Polygon::new(vec![[0, 0], [4, 0], [4, 4], [0, 4]]);
+        assert_eq!(square.double_area(), 32);
+        assert_eq!(square.area(), 16.0);
+        assert_eq!(square.boundary_points(), 16);
+        assert_eq!(square.interior_points(), 9);
+        assert_eq!(square.enclosed_points(), 25);
+    }
+
+    #[test]
+    fn test_from_moves() {
+        // an L shape traced clockwise with collinear intermediate vertices.
+        let moves = [
+            ([1, 0], 2),
+            ([1, 0], 1),
+            ([0, 1], 1),
+            ([-1, 0], 2),
+            ([0, 1], 2),
+            ([-1, 0], 1),
+            ([0, -1], 3),
+        ];
+        let shape = Polygon::from_moves(moves);
+        assert_eq!(shape.vertices.len(), 7);
+        assert_eq!(shape.double_area(), 10);
+        assert_eq!(shape.boundary_points(), 12);
+        assert_eq!(shape.interior_points(), 0);
+        assert_eq!(shape.enclosed_points(), 12);
+    }
+}
@@ -0,0 +1,283 @@
+//! Rendering grids to the terminal, to image files and to frame sequences.
+//!
+//! Nothing is drawn unless the binary was started with `--visualize`, e.g.
+//! `cargo solve 10 -- --visualize`, so solutions can call into this module
+//! unconditionally and release timings are unaffected. Files are written below
+//! `visualize/` in the working directory.
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+pub const OUTPUT_DIR: &str = "visualize";
+
+/// Whether `--visualize` was passed on the command line.
+pub fn enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| std::env::args().skip(1).any(|arg| arg == "--visualize"))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GREY: Rgb = Rgb(128, 128, 128);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(133, 153, 0);
+    pub const YELLOW: Rgb = Rgb(181, 137, 0);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+}
+
+/// One grid cell: the character shown in the terminal and an optional color.
+/// Uncolored cells are drawn black in images if blank (`' '` or `'.'`) and grey
+/// otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub color: Option<Rgb>,
+}
+
+impl Cell {
+    pub fn new(ch: char) -> Self {
+        Self { ch, color: None }
+    }
+
+    pub fn color(self, color: Rgb) -> Self {
+        Self {
+            color: Some(color),
+            ..self
+        }
+    }
+
+    fn pixel(&self) -> Rgb {
+        match (self.color, self.ch) {
+            (Some(color), _) => color,
+            (None, ' ' | '.') => Rgb::BLACK,
+            (None, _) => Rgb::GREY,
+        }
+    }
+}
+
+impl From<char> for Cell {
+    fn from(ch: char) -> Self {
+        Self::new(ch)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl Canvas {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(usize, usize) -> Cell) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Maps every cell of a row-major grid with `f`.
+    pub fn from_grid<T>(grid: &[Vec<T>], mut f: impl FnMut(&T) -> Cell) -> Self {
+        let width = grid.first().map_or(0, |row| row.len());
+        Self::from_fn(width, grid.len(), |x, y| f(&grid[y][x]))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Cell {
+        self.cells[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, cell: Cell) {
+        self.cells[y * self.width + x] = cell;
+    }
+
+    /// The grid as lines of text, colored with ANSI escape sequences.
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                match cell.color {
+                    Some(Rgb(r, g, b)) => {
+                        out += &format!(
+                            "\x1b[38;2;{};{};{}m{}{}",
+                            r,
+                            g,
+                            b,
+                            cell.ch,
+                            crate::ANSI_RESET
+                        )
+                    }
+                    None => out.push(cell.ch),
+                }
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Prints the grid to stdout if visualization is enabled.
+    pub fn print(&self) {
+        if enabled() {
+            print!("{}", self.to_ansi());
+        }
+    }
+
+    /// RGB bytes with every cell drawn as a `scale`×`scale` square.
+    fn pixels(&self, scale: usize) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(self.cells.len() * scale * scale * 3);
+        for row in self.cells.chunks(self.width.max(1)) {
+            for _ in 0..scale {
+                for cell in row {
+                    let Rgb(r, g, b) = cell.pixel();
+                    for _ in 0..scale {
+                        pixels.extend([r, g, b]);
+                    }
+                }
+            }
+        }
+        pixels
+    }
+
+    /// Writes a binary PPM (P6) image.
+    pub fn write_ppm(&self, path: impl AsRef<Path>, scale: usize) -> io::Result<()> {
+        let mut file = BufWriter::new(create(path.as_ref())?);
+        write!(
+            file,
+            "P6\n{} {}\n255\n",
+            self.width * scale,
+            self.height * scale
+        )?;
+        file.write_all(&self.pixels(scale))?;
+        file.flush()
+    }
+
+    pub fn write_png(&self, path: impl AsRef<Path>, scale: usize) -> io::Result<()> {
+        let file = BufWriter::new(create(path.as_ref())?);
+        let mut encoder = png::Encoder::new(
+            file,
+            (self.width * scale) as u32,
+            (self.height * scale) as u32,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels(scale))?;
+        Ok(writer.finish()?)
+    }
+
+    /// Writes `visualize/<name>.png` if visualization is enabled.
+    pub fn save(&self, name: &str, scale: usize) -> io::Result<()> {
+        if !enabled() {
+            return Ok(());
+        }
+        let path = Path::new(OUTPUT_DIR).join(format!("{}.png", name));
+        self.write_png(&path, scale)?;
+        eprintln!("wrote {}", path.display());
+        Ok(())
+    }
+}
+
+/// Numbered PNG frames of a step-wise simulation, in `visualize/<name>/`.
+/// Turn them into an animation with e.g.
+/// `ffmpeg -i visualize/<name>/%05d.png <name>.gif`.
+pub struct Frames {
+    dir: Option<PathBuf>,
+    scale: usize,
+    count: usize,
+}
+
+impl Frames {
+    /// Clears frames left from an earlier run. Does nothing unless visualization
+    /// is enabled.
+    pub fn new(name: &str, scale: usize) -> io::Result<Self> {
+        let dir = if enabled() {
+            let dir = Path::new(OUTPUT_DIR).join(name);
+            if dir.exists() {
+                fs::remove_dir_all(&dir)?;
+            }
+            fs::create_dir_all(&dir)?;
+            Some(dir)
+        } else {
+            None
+        };
+        Ok(Self {
+            dir,
+            scale,
+            count: 0,
+        })
+    }
+
+    pub fn push(&mut self, canvas: &Canvas) -> io::Result<()> {
+        if let Some(dir) = &self.dir {
+            canvas.write_png(dir.join(format!("{:05}.png", self.count)), self.scale)?;
+            self.count += 1;
+        }
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+fn create(path: &Path) -> io::Result<File> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    File::create(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canvas() -> Canvas {
+        Canvas::from_grid(&[vec![1, 0], vec![0, 2]], |&v| match v {
+            0 => Cell::new('.'),
+            1 => Cell::new('#'),
+            _ => Cell::new('@').color(Rgb::RED),
+        })
+    }
+
+    #[test]
+    fn test_to_ansi() {
+        assert_eq!(canvas().to_ansi(), "#.\n.\x1b[38;2;220;50;47m@\x1b[0m\n");
+    }
+
+    #[test]
+    fn test_write_images() {
+        let dir = std::env::temp_dir().join("aoc-visualize-test");
+        canvas().write_ppm(dir.join("grid.ppm"), 2).unwrap();
+        let ppm = fs::read(dir.join("grid.ppm")).unwrap();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+        assert_eq!(&ppm[11..14], &[128, 128, 128]);
+        assert_eq!(&ppm[ppm.len() - 3..], &[220, 50, 47]);
+
+        canvas().write_png(dir.join("grid.png"), 1).unwrap();
+        let png = fs::read(dir.join("grid.png")).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,297 @@
+/*
+ * Private leaderboards, read from the JSON that adventofcode.com serves at
+ * `/{year}/leaderboard/private/view/{id}.json`. See `src/bin/leaderboard.rs`.
+ */
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    /// Star timestamps by day and part.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sort {
+    Score,
+    Stars,
+    Name,
+    /// Time from the first to the second star, on `--day` or on average.
+    Delta,
+}
+
+impl FromStr for Sort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "score" => Ok(Sort::Score),
+            "stars" => Ok(Sort::Stars),
+            "name" => Ok(Sort::Name),
+            "delta" => Ok(Sort::Delta),
+            _ => Err(format!(
+                "unknown sort `{}`, expected score, stars, name or delta",
+                s
+            )),
+        }
+    }
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    pub fn star_ts(&self, day: u8, part: u8) -> Option<i64> {
+        Some(self.completion_day_level.get(&day)?.get(&part)?.get_star_ts)
+    }
+
+    pub fn stars_on(&self, day: u8) -> usize {
+        self.completion_day_level.get(&day).map_or(0, BTreeMap::len)
+    }
+
+    /// Seconds from the first to the second star of `day`.
+    pub fn delta(&self, day: u8) -> Option<i64> {
+        Some(self.star_ts(day, 2)? - self.star_ts(day, 1)?)
+    }
+
+    /// The mean [`delta`](Self::delta) over the days with both stars.
+    pub fn mean_delta(&self) -> Option<i64> {
+        let deltas = self
+            .completion_day_level
+            .keys()
+            .filter_map(|&day| self.delta(day))
+            .collect::<Vec<_>>();
+        (!deltas.is_empty()).then(|| deltas.iter().sum::<i64>() / deltas.len() as i64)
+    }
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// The last day any member has a star on.
+    pub fn last_day(&self) -> u8 {
+        self.members
+            .values()
+            .filter_map(|member| member.completion_day_level.keys().max().copied())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The members in `sort` order, best first. Ties are broken by score, then name.
+    pub fn sorted(&self, sort: Sort, day: Option<u8>) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<_>>();
+        members.sort_by(|a, b| {
+            let delta = |m: &Member| {
+                day.map_or_else(|| m.mean_delta(), |day| m.delta(day))
+                    .unwrap_or(i64::MAX)
+            };
+            let order = match sort {
+                Sort::Score => b.local_score.cmp(&a.local_score),
+                Sort::Stars => b.stars.cmp(&a.stars),
+                Sort::Name => a.display_name().cmp(&b.display_name()),
+                Sort::Delta => delta(a).cmp(&delta(b)),
+            };
+            order
+                .then(b.local_score.cmp(&a.local_score))
+                .then(a.display_name().cmp(&b.display_name()))
+        });
+        members
+    }
+
+    /// One row per member with their score, stars and a star per day.
+    pub fn render(&self, sort: Sort) -> String {
+        let last_day = self.last_day();
+        let members = self.sorted(sort, None);
+        let width = name_width(&members);
+        let mut out = String::new();
+        let days = (1..=last_day)
+            .map(|day| char::from_digit(day as u32 % 10, 10).unwrap_or(' '))
+            .collect::<String>();
+        let _ = writeln!(
+            out,
+            "{:>3}  {:<width$}  {:>5}  {:>5}  {}  {:>9}",
+            "#", "Name", "Score", "Stars", days, "Mean Δ"
+        );
+        for (rank, member) in members.iter().enumerate() {
+            let days = (1..=last_day)
+                .map(|day| match member.stars_on(day) {
+                    0 => '.',
+                    1 => '☆',
+                    _ => '★',
+                })
+                .collect::<String>();
+            let _ = writeln!(
+                out,
+                "{:>3}  {:<width$}  {:>5}  {:>5}  {}  {:>9}",
+                rank + 1,
+                member.display_name(),
+                member.local_score,
+                member.stars,
+                days,
+                member.mean_delta().map_or("-".to_string(), format_duration)
+            );
+        }
+        out
+    }
+
+    /// One row per member with their time to each star of `day`, from its unlock.
+    pub fn render_day(&self, day: u8, sort: Sort) -> String {
+        let unlock = unlock_ts(&self.event, day);
+        let members = self.sorted(sort, Some(day));
+        let width = name_width(&members);
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{:>3}  {:<width$}  {:>9}  {:>9}  {:>9}",
+            "#", "Name", "Part 1", "Part 2", "Δ"
+        );
+        for (rank, member) in members.iter().enumerate() {
+            let since_unlock = |part| {
+                let ts = member.star_ts(day, part)?;
+                Some(format_duration(ts - unlock?))
+            };
+            let _ = writeln!(
+                out,
+                "{:>3}  {:<width$}  {:>9}  {:>9}  {:>9}",
+                rank + 1,
+                member.display_name(),
+                since_unlock(1).unwrap_or("-".to_string()),
+                since_unlock(2).unwrap_or("-".to_string()),
+                member.delta(day).map_or("-".to_string(), format_duration)
+            );
+        }
+        out
+    }
+}
+
+fn name_width(members: &[&Member]) -> usize {
+    members
+        .iter()
+        .map(|member| member.display_name().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4)
+}
+
+/// `HH:MM:SS`, prefixed with the days if it's a day or more.
+pub fn format_duration(seconds: i64) -> String {
+    let (days, seconds) = (seconds / 86400, seconds % 86400);
+    let hms = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    if days > 0 {
+        format!("{}d {}", days, hms)
+    } else {
+        hms
+    }
+}
+
+/// When `day`'s puzzle unlocks: midnight EST (05:00 UTC) on December `day`.
+pub fn unlock_ts(event: &str, day: u8) -> Option<i64> {
+    let year: i64 = event.parse().ok()?;
+    // days since 1970-01-01 of December `day`, from Howard Hinnant's `days_from_civil`.
+    let (y, m, d) = (year, 12, day as i64);
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (m - 3) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+    Some(days * 86400 + 5 * 3600)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> Leaderboard {
+        Leaderboard::parse(include_str!("fixtures/leaderboard.json")).unwrap()
+    }
+
+    fn names(members: &[&Member]) -> Vec<String> {
+        members.iter().map(|m| m.display_name()).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let leaderboard = fixture();
+        assert_eq!(leaderboard.event, "2023");
+        assert_eq!(leaderboard.members.len(), 4);
+        assert_eq!(leaderboard.last_day(), 3);
+        let ada = &leaderboard.members["101"];
+        assert_eq!(ada.stars_on(2), 2);
+        assert_eq!(ada.delta(1), Some(300));
+        assert_eq!(ada.mean_delta(), Some(1200));
+        assert_eq!(
+            leaderboard.members["104"].display_name(),
+            "(anonymous user #104)"
+        );
+    }
+
+    #[test]
+    fn test_sorted() {
+        let leaderboard = fixture();
+        let by = |sort, day| names(&leaderboard.sorted(sort, day));
+        assert_eq!(
+            by(Sort::Score, None),
+            ["Ada", "Grace", "Linus", "(anonymous user #104)"]
+        );
+        assert_eq!(
+            by(Sort::Name, None),
+            ["(anonymous user #104)", "Ada", "Grace", "Linus"]
+        );
+        assert_eq!(by(Sort::Delta, Some(1))[..2], ["Grace", "Ada"]);
+        assert_eq!(by(Sort::Stars, None)[0], "Ada");
+    }
+
+    #[test]
+    fn test_unlock_ts() {
+        // 2023-12-01T05:00:00Z
+        assert_eq!(unlock_ts("2023", 1), Some(1701406800));
+        assert_eq!(unlock_ts("2023", 25), Some(1703480400));
+        assert_eq!(unlock_ts("next year", 1), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let leaderboard = fixture();
+        let overview = leaderboard.render(Sort::Score);
+        let lines = overview.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 5);
+        assert!(lines[1].contains("Ada"));
+        assert!(lines[1].contains("★★★"));
+        assert!(lines[1].ends_with("00:20:00"));
+        assert!(lines[4].contains("☆.."));
+
+        let day = leaderboard.render_day(1, Sort::Score);
+        let ada = day.lines().nth(1).unwrap().split_whitespace();
+        assert!(ada.eq(["1", "Ada", "00:10:00", "00:15:00", "00:05:00"]));
+        assert_eq!(format_duration(90061), "1d 01:01:01");
+    }
+}
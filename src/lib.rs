@@ -5,14 +5,61 @@
  */
 use std::env;
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
+pub mod days;
+pub mod encrypt;
 pub mod helpers;
+pub mod leaderboard;
+pub mod markdown;
+pub mod runner;
 pub mod submit;
 
+pub use tracing;
+
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// The `main`s of a day's binary, `src/bin/NN.rs`, one for each of the `solve`,
+/// `submit1`, `submit2` and `fuzz` features.
+#[macro_export]
+macro_rules! main {
+    ($day:literal, $module:ident) => {
+        #[cfg(feature = "solve")]
+        fn main() {
+            use advent_of_code::days::$module::{part_one, part_two};
+            let input = &advent_of_code::read_file("inputs", $day);
+            advent_of_code::solve!(1, part_one, input);
+            advent_of_code::solve!(2, part_two, input);
+        }
+
+        #[cfg(feature = "submit1")]
+        fn main() {
+            use advent_of_code::days::$module::part_one;
+            let input = &advent_of_code::read_file("inputs", $day);
+            advent_of_code::submit::submit($day, 1, part_one, input);
+        }
+
+        #[cfg(feature = "submit2")]
+        fn main() {
+            use advent_of_code::days::$module::part_two;
+            let input = &advent_of_code::read_file("inputs", $day);
+            advent_of_code::submit::submit($day, 2, part_two, input);
+        }
+
+        #[cfg(feature = "fuzz")]
+        fn main() {
+            use advent_of_code::days::$module::{part_one, part_two};
+            advent_of_code::helpers::fuzz::run($day, |input| {
+                let _ = part_one(input);
+                let _ = part_two(input);
+            });
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! solve {
     ($part:expr, $solver:ident, $input:expr) => {{
@@ -20,6 +67,8 @@ macro_rules! solve {
         use std::fmt::Display;
         use std::time::{Duration, Instant};
 
+        advent_of_code::helpers::logging::init();
+        let _span = advent_of_code::tracing::info_span!("part", part = $part).entered();
         println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
         if true {
             //cfg!(debug_assertions) {
@@ -59,16 +108,101 @@ macro_rules! solve {
                 }
             }
         }
+        if advent_of_code::helpers::explain::enabled() {
+            let explanation = advent_of_code::helpers::explain::take();
+            print!("{}", advent_of_code::helpers::explain::render(&explanation));
+        }
     }};
 }
 
+/// Selects the input set in `src/inputs/<profile>/`, e.g. a teammate's inputs.
+pub const PROFILE_ENV_VAR: &str = "AOC_PROFILE";
+
+/// The input set selected with `AOC_PROFILE`, if any.
+pub fn profile() -> Option<String> {
+    env::var(PROFILE_ENV_VAR).ok().filter(|p| !p.is_empty())
+}
+
+/// The profiles with a directory in `src/inputs`, sorted.
+pub fn profiles() -> Vec<String> {
+    let mut profiles = fs::read_dir(Path::new("src").join("inputs"))
+        .map(|dir| {
+            dir.filter_map(|entry| entry.ok())
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().into_string().ok())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    profiles.sort();
+    profiles
+}
+
+/// `src/<folder>/NN.txt`, in the profile's directory for inputs.
+fn file_path(folder: &str, day: u8) -> PathBuf {
+    match profile().filter(|_| folder == "inputs") {
+        Some(profile) => input_path(Some(&profile), day),
+        None => Path::new("src")
+            .join(folder)
+            .join(format!("{:02}.txt", day)),
+    }
+}
+
+/// `src/inputs/NN.txt`, or `src/inputs/<profile>/NN.txt`.
+pub fn input_path(profile: Option<&str>, day: u8) -> PathBuf {
+    let mut path = Path::new("src").join("inputs");
+    if let Some(profile) = profile {
+        path.push(profile);
+    }
+    path.join(format!("{:02}.txt", day))
+}
+
+/// Reads `src/<folder>/NN.txt` as is, or decrypts `NN.txt.enc` if only that
+/// exists. With `AOC_PROFILE` set, inputs are read from `src/inputs/<profile>/`.
+/// Line endings are left alone, see [`helpers::bytes::lines`].
+pub fn read_bytes(folder: &str, day: u8) -> Vec<u8> {
+    read_path(&file_path(folder, day)).unwrap_or_else(|e| panic!("{}", e))
+}
+
+fn read_path(path: &Path) -> Result<Vec<u8>, String> {
+    let cwd = env::current_dir().map_err(|e| e.to_string())?;
+
+    let filepath = cwd.join(path);
+
+    if !filepath.exists() && encrypt::encrypted_path(&filepath).exists() {
+        encrypt::read_encrypted(&filepath)
+            .map(String::into_bytes)
+            .map_err(|e| format!("could not decrypt input file: {}", e))
+    } else {
+        fs::read(&filepath)
+            .map_err(|e| format!("could not open input file {}: {}", path.display(), e))
+    }
+}
+
+/// [`read_bytes`] as a string with `\r\n` line endings turned into `\n`.
 pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+    normalize(read_bytes(folder, day)).unwrap_or_else(|e| panic!("{}", e))
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// [`read_file`] for the input of `profile` rather than `AOC_PROFILE`'s, e.g. to
+/// run a day on every profile in one process. `None` is the default input set.
+/// A missing, undecryptable or non-UTF-8 input is an error instead of a panic.
+pub fn read_input(profile: Option<&str>, day: u8) -> Result<String, String> {
+    normalize(read_path(&input_path(profile, day))?)
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file").replace("\r\n", "\n")
+fn normalize(bytes: Vec<u8>) -> Result<String, String> {
+    let f = String::from_utf8(bytes).map_err(|_| "input file is not UTF-8".to_string())?;
+    if f.contains('\r') {
+        Ok(f.replace("\r\n", "\n"))
+    } else {
+        Ok(f)
+    }
+}
+
+/// Whether [`read_file`] finds the file, plain or encrypted.
+pub fn has_file(folder: &str, day: u8) -> bool {
+    let filepath = file_path(folder, day);
+    filepath.exists() || encrypt::encrypted_path(&filepath).exists()
 }
 
 fn parse_time(val: &str, postfix: &str) -> f64 {
@@ -98,6 +232,30 @@ pub fn parse_exec_time(output: &str) -> f64 {
     })
 }
 
+/// Parses a duration like `1s`, `250ms`, `1.5ms` or `500µs` (or `500us`).
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+    let value = value
+        .parse::<f64>()
+        .map_err(|_| format!("invalid duration `{}`", s))?;
+    let seconds = match unit {
+        "s" => value,
+        "ms" => value / 1e3,
+        "µs" | "us" => value / 1e6,
+        "ns" => value / 1e9,
+        _ => {
+            return Err(format!(
+                "invalid duration `{}`, expected a unit of s, ms, µs or ns",
+                s
+            ))
+        }
+    };
+    Duration::try_from_secs_f64(seconds).map_err(|e| format!("invalid duration `{}`: {}", s, e))
+}
+
 /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
 #[cfg(test)]
 macro_rules! assert_approx_eq {
@@ -143,4 +301,16 @@ mod tests {
             10400.50_f64
         );
     }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("1s"), Ok(Duration::from_secs(1)));
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration("1.5ms"), Ok(Duration::from_micros(1500)));
+        assert_eq!(parse_duration("500us"), parse_duration("500µs"));
+        assert!(parse_duration("1").is_err());
+        assert!(parse_duration("ms").is_err());
+        assert!(parse_duration("1h").is_err());
+        assert!(parse_duration("99999999999999999999999s").is_err());
+    }
 }
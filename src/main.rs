@@ -2,34 +2,159 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-            if is_empty {
-                return 0f64;
+use advent_of_code::runner::{self, Day};
+use advent_of_code::{days, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::process::{self, Command};
+
+fn run_day(day: u8) -> Option<String> {
+    let day = format!("{:02}", day);
+    let mut args = vec!["run", "--release", "--bin", &day];
+    if cfg!(feature = "parallel") {
+        args.extend(["--features", "parallel"]);
+    }
+
+    let cmd = Command::new("cargo").args(args).output().unwrap();
+    let output = String::from_utf8(cmd.stdout).unwrap();
+    if output.is_empty() {
+        None
+    } else {
+        Some(output)
+    }
+}
+
+/// Runs `day` in-process on its embedded example, printing like `solve!`. Also
+/// returns whether every checked part gave its expected answer.
+fn run_example(day: &Day) -> (String, bool) {
+    let mut output = String::new();
+    let mut correct = true;
+    for ((part, solver), expected) in (1..=2).zip(day.parts).zip(day.answers) {
+        let outcome = runner::run(solver, day.example);
+        output += &format!("🎄 {}Part {}{} 🎄\n", ANSI_BOLD, part, ANSI_RESET);
+        output += &match &outcome.answer {
+            Ok(Some(answer)) => format!(
+                "{} {}(elapsed: {:.2?}){}\n",
+                answer, ANSI_ITALIC, outcome.elapsed, ANSI_RESET
+            ),
+            Ok(None) => "not solved.\n".to_string(),
+            Err(message) => format!("panicked: {}\n", message),
+        };
+        if let Some(expected) = expected {
+            if !outcome.is(expected) {
+                correct = false;
+                output += &format!("{}expected {}{}\n", ANSI_BOLD, expected, ANSI_RESET);
             }
+        }
+    }
+    (output, correct)
+}
+
+/// Prints the days that took the largest share of `total`, most expensive first.
+fn print_largest_shares(times: &[(u8, f64)], total: f64) {
+    let mut times = times.to_vec();
+    times.sort_by(|a, b| b.1.total_cmp(&a.1));
+    for (day, time) in times.iter().take(5) {
+        println!(
+            "  Day {:02}: {:.2}ms ({:.0}%)",
+            day,
+            time,
+            time / total * 100.0
+        );
+    }
+}
+
+fn main() {
+    // `cargo all -- --profile <name>` runs every day on that input set, like
+    // `AOC_PROFILE=<name>`; see `read_file`.
+    let mut args = pico_args::Arguments::from_env();
+    if let Ok(Some(profile)) = args.opt_value_from_str::<_, String>("--profile") {
+        std::env::set_var(advent_of_code::PROFILE_ENV_VAR, profile);
+    }
+
+    // `cargo all -- --budget 1s --day-budget 100ms` fails the run if all days, or
+    // any single day, take longer than that.
+    let mut budget = |name| {
+        args.opt_value_from_fn(name, advent_of_code::parse_duration)
+            .unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                process::exit(1);
+            })
+            .map(|budget| budget.as_secs_f64() * 1000.0)
+    };
+    let total_budget = budget("--budget");
+    let day_budget = budget("--day-budget");
+
+    // `cargo all -- --examples` runs every day on its example instead, in-process,
+    // and fails the run if a day's answers don't match the ones in its module.
+    let examples = args.contains("--examples");
+
+    let unknown = args.finish();
+    if !unknown.is_empty() {
+        eprintln!("Unknown arguments: {:?}", unknown);
+        process::exit(1);
+    }
+    let run = |day: u8| {
+        if examples {
+            days::ALL.iter().find(|d| d.day == day).map(run_example)
+        } else {
+            run_day(day).map(|output| (output, true))
+        }
+    };
+
+    // with `parallel`, days run concurrently and are printed once all are done. A
+    // budget times them one at a time instead, as concurrent days compete for cores.
+    #[cfg(feature = "parallel")]
+    let outputs = if total_budget.is_some() || day_budget.is_some() {
+        (1..=25).map(|day| (day, run(day))).collect::<Vec<_>>()
+    } else {
+        use rayon::prelude::*;
+        (1..=25)
+            .into_par_iter()
+            .map(|day| (day, run(day)))
+            .collect::<Vec<_>>()
+    };
+    #[cfg(not(feature = "parallel"))]
+    let outputs = (1..=25).map(|day| (day, run(day)));
+
+    let mut failed = false;
+    let times = outputs
+        .into_iter()
+        .filter_map(|(day, output)| Some((day, output?)))
+        .map(|(day, (output, correct))| {
             println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
+            println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
             println!("----------");
 
             println!("{}", output.trim());
+            failed |= !correct;
 
-            advent_of_code::parse_exec_time(&output)
+            (day, advent_of_code::parse_exec_time(&output))
         })
-        .sum();
+        .collect::<Vec<_>>();
+    let total: f64 = times.iter().map(|(_, time)| time).sum();
 
     println!(
         "{}Total:{} {}{:.2}ms{}",
         ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
     );
+
+    if let Some(budget) = total_budget.filter(|&budget| total > budget) {
+        failed = true;
+        println!(
+            "{}Over budget:{} {:.2}ms of {:.2}ms. Largest shares:",
+            ANSI_BOLD, ANSI_RESET, total, budget
+        );
+        print_largest_shares(&times, total);
+    }
+    if let Some(budget) = day_budget {
+        for (day, time) in times.iter().filter(|(_, time)| *time > budget) {
+            failed = true;
+            println!(
+                "{}Day {:02} over budget:{} {:.2}ms of {:.2}ms",
+                ANSI_BOLD, day, ANSI_RESET, time, budget
+            );
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
 }
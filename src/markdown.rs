@@ -0,0 +1,235 @@
+/*
+ * Renders the markdown of a puzzle description (as written by aoc-cli) for the
+ * terminal: headings and emphasis in bold, code in green, links as their text,
+ * paragraphs and list items wrapped. See `src/bin/read.rs`.
+ */
+use crate::{ANSI_BOLD, ANSI_RESET};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const ANSI_CODE: &str = "\x1b[32m";
+
+#[derive(Default)]
+struct Style {
+    strong: bool,
+    code: bool,
+}
+
+impl Style {
+    fn ansi(&self) -> String {
+        let mut ansi = ANSI_RESET.to_string();
+        if self.strong {
+            ansi += ANSI_BOLD;
+        }
+        if self.code {
+            ansi += ANSI_CODE;
+        }
+        ansi
+    }
+}
+
+/// Replaces `[text](url)` with `text`.
+fn strip_links(text: &str) -> String {
+    static LINK: OnceLock<Regex> = OnceLock::new();
+    let link = LINK.get_or_init(|| Regex::new(r"\[([^\]]*)\]\([^)]*\)").unwrap());
+    link.replace_all(text, "$1").into_owned()
+}
+
+/// A word with its markup turned into ANSI escapes, and its visible width.
+fn render_word(word: &str, style: &mut Style) -> (String, usize) {
+    let mut out = String::new();
+    let mut width = 0;
+    let mut chars = word.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if !style.code => {
+                if let Some(escaped) = chars.next() {
+                    out.push(escaped);
+                    width += 1;
+                }
+            }
+            '`' => {
+                style.code = !style.code;
+                out += &style.ansi();
+            }
+            '*' if !style.code => {
+                style.strong = !style.strong;
+                out += &style.ansi();
+            }
+            c => {
+                out.push(c);
+                width += 1;
+            }
+        }
+    }
+    (out, width)
+}
+
+/// Wraps `text` to `width` columns, starting lines after the first with `indent`.
+fn wrap(text: &str, width: usize, first: &str, indent: &str) -> String {
+    let mut style = Style::default();
+    let mut out = first.to_string();
+    let mut column = first.chars().count();
+    let mut line_empty = true;
+    for word in strip_links(text).split_whitespace() {
+        let active = style.ansi();
+        let (rendered, len) = render_word(word, &mut style);
+        if !line_empty && column + 1 + len > width {
+            out += ANSI_RESET;
+            out.push('\n');
+            out += indent;
+            out += &active;
+            column = indent.chars().count();
+            line_empty = true;
+        }
+        if !line_empty {
+            out.push(' ');
+            column += 1;
+        }
+        out += &rendered;
+        column += len;
+        line_empty = false;
+    }
+    out += ANSI_RESET;
+    out
+}
+
+/// `text` without ANSI escapes, e.g. [`render`]'s output for a plain display.
+pub fn strip_ansi(text: &str) -> String {
+    static ESCAPE: OnceLock<Regex> = OnceLock::new();
+    let escape = ESCAPE.get_or_init(|| Regex::new("\x1b\\[[0-9;]*m").unwrap());
+    escape.replace_all(text, "").into_owned()
+}
+
+/// Renders `markdown` for a terminal `width` columns wide.
+pub fn render(markdown: &str, width: usize) -> String {
+    let mut blocks = Vec::new();
+    let mut paragraph = Vec::new();
+    let mut lines = markdown.lines();
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<String>| {
+        if !paragraph.is_empty() {
+            blocks.push(wrap(&paragraph.join(" "), width, "", ""));
+            paragraph.clear();
+        }
+    };
+    let heading = |text: &str| format!("{}{}{}", ANSI_BOLD, strip_links(text), ANSI_RESET);
+    // a line of only `=` or `-` under a paragraph makes it a (setext) heading.
+    let underline = |line: &str| {
+        !line.is_empty() && (line.bytes().all(|b| b == b'=') || line.bytes().all(|b| b == b'-'))
+    };
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            flush(&mut paragraph, &mut blocks);
+            let code = lines
+                .by_ref()
+                .take_while(|line| !line.trim().starts_with("```"))
+                .map(|line| format!("    {}{}{}", ANSI_CODE, line, ANSI_RESET))
+                .collect::<Vec<_>>();
+            blocks.push(code.join("\n"));
+        } else if trimmed.starts_with('#') {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(heading(trimmed.trim_start_matches('#').trim()));
+        } else if !paragraph.is_empty() && underline(trimmed) {
+            blocks.push(heading(&paragraph.join(" ")));
+            paragraph.clear();
+        } else if let Some(item) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|bullet| trimmed.strip_prefix(bullet))
+        {
+            flush(&mut paragraph, &mut blocks);
+            // continuation lines of the item are indented.
+            let mut item = item.to_string();
+            while let Some(next) = lines.clone().next().filter(|l| l.starts_with("  ")) {
+                item += " ";
+                item += next.trim();
+                lines.next();
+            }
+            blocks.push(wrap(&item, width, "  • ", "    "));
+        } else if trimmed.is_empty() {
+            flush(&mut paragraph, &mut blocks);
+        } else {
+            paragraph.push(trimmed);
+        }
+    }
+    flush(&mut paragraph, &mut blocks);
+
+    // consecutive list items stay together.
+    let mut out = String::new();
+    for (i, block) in blocks.iter().enumerate() {
+        if i > 0 {
+            let list = |b: &str| b.starts_with("  • ");
+            out += if list(block) && list(&blocks[i - 1]) {
+                "\n"
+            } else {
+                "\n\n"
+            };
+        }
+        out += block;
+    }
+    out.push('\n');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let markdown = "\
+## --- Day 1: Trebuchet?! ---
+
+Something is wrong with global snow production, and you've been selected to take a look.
+The [Elves](/2023/about) have even given you a map.
+
+- `1abc2` has *twelve*
+- and `pqr3stu8vwx` has
+  *thirty-eight*
+
+```
+1abc2
+pqr3stu8vwx
+```
+";
+        let rendered = render(markdown, 40);
+        assert!(rendered.starts_with(&format!("{}--- Day 1", ANSI_BOLD)));
+        assert!(rendered.contains(&format!("{}{}1abc2", ANSI_RESET, ANSI_CODE)));
+        assert_eq!(
+            strip_ansi(&rendered),
+            "\
+--- Day 1: Trebuchet?! ---
+
+Something is wrong with global snow
+production, and you've been selected to
+take a look. The Elves have even given
+you a map.
+
+  • 1abc2 has twelve
+  • and pqr3stu8vwx has thirty-eight
+
+    1abc2
+    pqr3stu8vwx
+"
+        );
+
+        let rendered = render("Part Two\n========\n\nYour [calculation](x)\n---\n", 40);
+        assert_eq!(
+            rendered,
+            format!(
+                "{}Part Two{}\n\n{}Your calculation{}\n",
+                ANSI_BOLD, ANSI_RESET, ANSI_BOLD, ANSI_RESET
+            )
+        );
+    }
+
+    #[test]
+    fn test_wrap_keeps_style() {
+        let rendered = wrap("a *b c* d", 3, "", "");
+        assert_eq!(strip_ansi(&rendered), "a b\nc d");
+        // the emphasis is picked up again after the line break.
+        let second = rendered.lines().nth(1).unwrap();
+        assert!(second.starts_with(&format!("{}{}c", ANSI_RESET, ANSI_BOLD)));
+    }
+}
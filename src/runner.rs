@@ -0,0 +1,182 @@
+/*
+ * Runs solutions in-process, for tools that drive several days at once
+ * (`src/bin/calendar.rs`). A day's parts are adapted to [`Solver`]s with [`day!`],
+ * and its example is embedded with [`example!`].
+ */
+use crate::helpers::generate::Generator;
+use std::any::Any;
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+/// A part, with its answer formatted.
+pub type Solver = fn(&str) -> Option<String>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub parts: [Solver; 2],
+    /// The example, `src/examples/NN.txt`, embedded at compile time.
+    pub example: &'static str,
+    /// What each part gives on the example, `None` where it isn't checked.
+    pub answers: [Option<&'static str>; 2],
+    pub generate: Option<Generator>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    /// The answer, `None` if not solved, or the message it panicked with.
+    pub answer: Result<Option<String>, String>,
+    pub elapsed: Duration,
+}
+
+impl Outcome {
+    /// Whether the part answered `expected`.
+    pub fn is(&self, expected: &str) -> bool {
+        self.answer
+            .as_ref()
+            .is_ok_and(|answer| answer.as_deref() == Some(expected))
+    }
+}
+
+/// `Day` for a module with `part_one`, `part_two` and [`example!`](crate::example), and `generate`
+/// if passed.
+#[macro_export]
+macro_rules! day {
+    ($day:expr, $module:ident, generate) => {
+        $crate::runner::Day {
+            generate: Some($module::generate),
+            ..$crate::day!($day, $module)
+        }
+    };
+    ($day:expr, $module:ident) => {
+        $crate::runner::Day {
+            day: $day,
+            parts: [
+                |input| $module::part_one(input).map(|answer| answer.to_string()),
+                |input| $module::part_two(input).map(|answer| answer.to_string()),
+            ],
+            example: $module::EXAMPLE,
+            answers: $module::ANSWERS,
+            generate: None,
+        }
+    };
+}
+
+/// Declares a day's `EXAMPLE`, `src/examples/NN.txt` embedded at compile time, and
+/// `ANSWERS`, what each part gives on it. Each answer is a literal, or `_` if the
+/// part isn't checked, e.g. because the example is only for the other part. Checked
+/// parts get a test.
+///
+/// ```ignore
+/// crate::example!("08", _, 6);
+/// ```
+#[macro_export]
+macro_rules! example {
+    (@answer _) => {
+        None
+    };
+    (@answer $answer:literal) => {
+        Some(stringify!($answer))
+    };
+    (@test $name:ident, $part:ident, _) => {};
+    (@test $name:ident, $part:ident, $answer:literal) => {
+        #[test]
+        fn $name() {
+            $crate::helpers::logging::init_test();
+            assert_eq!(super::$part(super::EXAMPLE), Some($answer));
+        }
+    };
+    ($file:literal, $one:tt, $two:tt) => {
+        pub const EXAMPLE: &str = include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/examples/",
+            $file,
+            ".txt"
+        ));
+
+        pub const ANSWERS: [Option<&str>; 2] =
+            [$crate::example!(@answer $one), $crate::example!(@answer $two)];
+
+        #[cfg(test)]
+        mod examples {
+            $crate::example!(@test test_part_one, part_one, $one);
+            $crate::example!(@test test_part_two, part_two, $two);
+        }
+    };
+}
+
+thread_local! {
+    static RUNNING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Wraps the panic hook so that it stays quiet for panics on a thread inside [`run`],
+/// which reports them in its [`Outcome`] instead. Other panics, including ones on
+/// threads a solver spawns, still reach the previous hook.
+fn quiet_panics_in_run() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !RUNNING.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// The message a panic was raised with, from `panic!` or e.g. `expect`.
+pub fn panic_message(payload: &dyn Any) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panicked".to_string())
+}
+
+/// Runs `solver` on `input`, catching panics.
+pub fn run(solver: Solver, input: &str) -> Outcome {
+    quiet_panics_in_run();
+    let timer = Instant::now();
+    RUNNING.with(|running| running.set(true));
+    let answer = panic::catch_unwind(AssertUnwindSafe(|| solver(input)));
+    RUNNING.with(|running| running.set(false));
+    Outcome {
+        answer: answer.map_err(|payload| panic_message(&*payload)),
+        elapsed: timer.elapsed(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod sum {
+        pub const EXAMPLE: &str = "1\n2\n";
+        pub const ANSWERS: [Option<&str>; 2] = [Some("3"), None];
+
+        pub fn part_one(input: &str) -> Option<u32> {
+            input.lines().map(|l| l.parse::<u32>().ok()).sum()
+        }
+
+        pub fn part_two(_: &str) -> Option<u32> {
+            panic!("not yet")
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let day = day!(1, sum);
+        assert_eq!(day.day, 1);
+        assert_eq!(day.answers, [Some("3"), None]);
+        assert_eq!(
+            run(day.parts[0], day.example).answer,
+            Ok(Some("3".to_string()))
+        );
+        assert_eq!(run(day.parts[0], "x").answer, Ok(None));
+        assert_eq!(run(day.parts[1], "").answer, Err("not yet".to_string()));
+        assert!(run(day.parts[0], day.example).is("3"));
+        assert!(!run(day.parts[0], "x").is("3"));
+    }
+}
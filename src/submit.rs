@@ -1,31 +1,74 @@
-use std::fmt::Display;
 /*
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
 use std::io::Write;
+use std::path::Path;
 use std::process;
 use std::{io, process::Command};
 
-pub fn submit(
+/// Correct answers, one `<day> <part> <answer>` line per star.
+pub const LEDGER: &str = "submissions.txt";
+
+/// Appends a correct answer to the ledger.
+fn record(day: u32, part: u32, answer: &str) -> io::Result<()> {
+    let mut ledger = OpenOptions::new().create(true).append(true).open(LEDGER)?;
+    writeln!(ledger, "{} {} {}", day, part, answer)
+}
+
+/// The answers in the ledger by day and part, empty if there is none yet.
+pub fn ledger() -> BTreeMap<(u8, u8), String> {
+    read_answers(Path::new(LEDGER))
+}
+
+/// Answers in the ledger's format by day and part, empty if the file doesn't exist.
+pub fn read_answers(path: &Path) -> BTreeMap<(u8, u8), String> {
+    fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, ' ');
+            let day = fields.next()?.parse().ok()?;
+            let part = fields.next()?.parse().ok()?;
+            Some(((day, part), fields.next()?.to_string()))
+        })
+        .collect()
+}
+
+pub fn submit<T: Display>(
     day: u32,
     part: u32,
-    solver: impl FnOnce(&str) -> Option<Box<dyn Display>>,
+    solver: impl FnOnce(&str) -> Option<T>,
     input: &str,
 ) {
-    let answer = solver(input);
-    match &answer {
-        Some(answer) => {
-            println!("Submitting answer: {}", answer);
+    let answer = match solver(input) {
+        Some(answer) => answer.to_string(),
+        None => panic!("Couldn't solve"),
+    };
+    println!("Submitting answer: {}", answer);
+
+    match submit_answer(day, part, &answer) {
+        Ok(output) => {
+            io::stdout()
+                .write_all(output.as_bytes())
+                .expect("could not write cmd stdout to pipe.");
         }
-        None => {
-            panic!("Couldn't solve")
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
         }
     }
+}
+
+/// Submits `answer` with aoc-cli, recording it in the ledger if it's right.
+/// Returns aoc-cli's output, or what went wrong.
+pub fn submit_answer(day: u32, part: u32, answer: &str) -> Result<String, String> {
     // check if aoc binary exists and is callable.
     if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+        return Err("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.".to_string());
     }
 
     let mut cmd_args = vec![];
@@ -38,29 +81,26 @@ pub fn submit(
         day.to_string(),
         "submit".into(),
         part.to_string(),
+        answer.to_string(),
     ]);
 
-    println!(
-        "Submitting output with >aoc {} {}",
-        cmd_args.join(" "),
-        answer.unwrap()
-    );
-
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stdout to pipe.");
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            process::exit(1);
+    let cmd_output = Command::new("aoc")
+        .args(&cmd_args)
+        .output()
+        .map_err(|e| format!("failed to spawn aoc-cli: {}", e))?;
+    let stdout = String::from_utf8_lossy(&cmd_output.stdout).into_owned();
+    if !cmd_output.status.success() {
+        return Err(format!(
+            ">aoc {} failed:\n{}{}",
+            cmd_args.join(" "),
+            stdout,
+            String::from_utf8_lossy(&cmd_output.stderr)
+        ));
+    }
+    if stdout.contains("That's the right answer") {
+        if let Err(e) = record(day, part, answer) {
+            return Err(format!("failed to record the answer in {}: {}", LEDGER, e));
         }
     }
+    Ok(stdout)
 }